@@ -7,6 +7,8 @@ pub trait Bitmanip {
     fn contains_index(self, square_index: u64) -> bool;
     fn contains_bit(self, bit: u64) -> bool;
     fn isolate_ls1b(self) -> u64;
+    fn flip_vertical(self) -> u64;
+    fn flip_horizontal(self) -> u64;
 }
 
 impl Bitmanip for u64 {
@@ -22,7 +24,7 @@ impl Bitmanip for u64 {
 
     #[inline]
     fn toggle_squares(&mut self, square_index_1: u64, square_index_2: u64) {
-        *self ^= (1 << square_index_1) | (1 << square_index_2);
+        *self ^= (1 << square_index_1) ^ (1 << square_index_2);
     }
 
     #[inline]
@@ -44,24 +46,38 @@ impl Bitmanip for u64 {
 
     #[inline]
     fn isolate_ls1b(self) -> u64 {
-        self & (-(self as i64)) as u64
+        self & self.wrapping_neg()
     }
 
     #[inline]
     fn contains_bit(self, bit: u64) -> bool {
         (self & bit) != 0
     }
+
+    /// Rank 1 becomes rank 8, the files stay the same
+    #[inline]
+    fn flip_vertical(self) -> u64 {
+        self.swap_bytes()
+    }
+
+    /// File a becomes file h, the ranks stay the same
+    #[inline]
+    fn flip_horizontal(self) -> u64 {
+        self.reverse_bits().swap_bytes()
+    }
 }
 
-pub fn print_bitboard(bitboard: u64) {
-    println!();
+
+/// The bitboard as 8 lines of 0s and 1s, rank 8 first and file a on the left
+pub fn bitboard_rows(bitboard: u64) -> [String; 8] {
     const LAST_BIT: u64 = 63;
-    for rank in 0..8 {
+    std::array::from_fn(|rank| {
+        let mut row = String::with_capacity(16);
         for file in (0..8).rev() {
-            let mask = 1u64 << (LAST_BIT - (rank * 8) - file);
-            let char = if bitboard & mask != 0 { '1' } else { '0' };
-            print!("{char} ");
+            let mask = 1u64 << (LAST_BIT - (rank as u64 * 8) - file);
+            row.push(if bitboard & mask != 0 { '1' } else { '0' });
+            row.push(' ');
         }
-        println!();
-    }
+        row
+    })
 }
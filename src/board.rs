@@ -1,6 +1,23 @@
-use crate::{bitboard::*, chess_move::*, gamestate::Gamestate, move_generation::generate_legal_moves, move_list::MoveList, piece::*};
-use std::collections::HashMap;
+use std::fmt;
 
+use crate::{
+    bitboard::*,
+    chess960,
+    chess_move::*,
+    consts::CONSTS,
+    fen::{self, FenError, FenField},
+    game_status::GameStatus,
+    gamestate::{CastleSide, Gamestate},
+    material::MaterialSignature,
+    move_generation::{self, generate_legal_moves},
+    move_list::MoveList,
+    piece::*,
+    psqt::{self, Score},
+    validation::ValidationError,
+    zobrist::ZOBRIST,
+};
+
+#[derive(Clone)]
 pub struct Board {
     pieces_bb: [[u64; 6]; 2],
     colors_bb: [u64; 2],
@@ -9,58 +26,91 @@ pub struct Board {
     legal_moves: MoveList,
     pub current_gamestate: Gamestate,
     gamestate_stack: Vec<Gamestate>,
+    move_history: Vec<Move>,       // played moves, null moves are not recorded
+    castling_rooks: [[u64; 2]; 2], // starting square of the rook tied to each castling right, [color][castle side]
+    castling_masks: [u8; 64],      // castling rights kept when a piece moves from or to the square
+    chess960: bool,
+}
+
+/// A snapshot of a position without the move history and the legal move list, small enough to be copied around freely.
+/// Turning it back into a Board regenerates what was left out, but the new board can't detect earlier repetitions
+#[derive(Copy, Clone, Debug)]
+pub struct Position {
+    pieces_bb: [[u64; 6]; 2],
+    color_to_move: PieceColor,
+    castling_rooks: [[u64; 2]; 2],
+    gamestate: Gamestate,
 }
 
 impl Board {
+    /// Convenience constructor for FEN strings known to be valid, panics otherwise
     pub fn new(fen_string: &str) -> Board {
-        let char_to_type: HashMap<char, PieceType> = HashMap::from([
-            ('r', PieceType::Rook),
-            ('n', PieceType::Knight),
-            ('b', PieceType::Bishop),
-            ('q', PieceType::Queen),
-            ('k', PieceType::King),
-            ('p', PieceType::Pawn),
-        ]); // CONST
-
-        let mut bb_pieces: [[u64; 6]; 2] = [[0; 6]; 2];
-        let fen_string_splits: Vec<&str> = fen_string.split(' ').collect();
-
-        let mut rank: u64 = 0;
-        let mut file: u64 = 0;
-        for c in fen_string_splits[0].chars() {
-            match c {
-                '/' => {
-                    if file != 8 {
-                        panic!("Error in fen string parsing")
-                    }
-
-                    rank += 1;
-                    file = 0;
-                }
-                '1'..='8' => file += c.to_digit(10).unwrap() as u64,
-                'a'..='z' => {
-                    let bb_index = (7 - rank) * 8 + file;
-                    let piece_type = char_to_type[&c];
-                    bb_pieces[PieceColor::Black][piece_type].set_square(bb_index);
-                    file += 1;
-                }
-                'A'..='Z' => {
-                    let bb_index = (7 - rank) * 8 + file;
-                    let piece_type = char_to_type[&c.to_ascii_lowercase()];
-                    bb_pieces[PieceColor::White][piece_type].set_square(bb_index);
-                    file += 1;
+        match Self::from_fen(fen_string) {
+            Ok(board) => board,
+            Err(e) => panic!("invalid FEN string \"{fen_string}\": {e}"),
+        }
+    }
+
+    pub fn from_fen(fen_string: &str) -> Result<Board, FenError> {
+        let fields = fen::split_fields(fen_string)?;
+
+        let bb_pieces = fen::parse_piece_placement(fields[0].1, fields[0].0)?;
+        let color_to_move = fen::parse_color_to_move(fields[1].1, fields[1].0)?;
+        let (castling_rights, castling_rooks) = fen::parse_castling_rights(fields[2].1, fields[2].0, &bb_pieces)?;
+        let enpassant_square = fen::parse_enpassant_square(fields[3].1, fields[3].0)?;
+
+        // the move counters are optional, FENs without them start counting from "0 1"
+        let halfmove_clock = match fields.get(4) {
+            Some(&(offset, field)) => fen::parse_counter(field, offset, FenField::HalfmoveClock)?,
+            None => 0,
+        };
+        let fullmove_number = match fields.get(5) {
+            Some(&(offset, field)) => fen::parse_counter(field, offset, FenField::FullmoveNumber)?,
+            None => 1,
+        };
+
+        let mut res = Self::empty();
+        res.current_gamestate = Gamestate::new(0, None, halfmove_clock, fullmove_number);
+        for (color_index, bb_ar) in bb_pieces.iter().enumerate() {
+            for (piece_index, bb) in bb_ar.iter().enumerate() {
+                let mut bb_copy = *bb;
+                while bb_copy != 0 {
+                    let index = bb_copy.bitscan_reset();
+                    res.put_piece(index, Piece::new(PieceType::from(piece_index), PieceColor::from(color_index)));
                 }
-                _ => {}
-            };
+            }
         }
+        res.set_side_to_move(color_to_move)
+            .set_castling_rights(castling_rights)
+            .set_en_passant(enpassant_square);
+        // the castling field may name inner rooks, so they are not looked up like finish does
+        res.castling_rooks = castling_rooks;
+
+        // move generation assumes a reachable position, exactly one king per side above all
+        if let Err(error) = res.complete_setup() {
+            let field_index = FenField::ALL.iter().position(|&field| field == error.fen_field()).unwrap();
+            return Err(FenError::IllegalPosition {
+                offset: fields[field_index].0,
+                error,
+            });
+        }
+
+        Ok(res)
+    }
 
+    /// Assembles a board from its piece bitboards, the other representations and the hash are derived from them.
+    /// Legal moves are not generated, so that callers can validate the position first
+    fn from_parts(
+        bb_pieces: [[u64; 6]; 2],
+        color_to_move: PieceColor,
+        castling_rooks: [[u64; 2]; 2],
+        gamestate: Gamestate,
+    ) -> Board {
         let bb_colors = [
             bb_pieces[0].iter().copied().fold(0, |acc, bb| acc | bb),
             bb_pieces[1].iter().copied().fold(0, |acc, bb| acc | bb),
         ];
 
-        let color_to_move = Self::read_color_to_move(fen_string_splits[1]);
-
         let mut piece_matrix = [None; 64];
         for (color_index, bb_ar) in bb_pieces.iter().enumerate() {
             for (piece_index, bb) in bb_ar.iter().enumerate() {
@@ -78,22 +128,523 @@ impl Board {
             color_to_move,
             piece_matrix,
             legal_moves: MoveList::new(),
-            current_gamestate: Gamestate::new(fen_string_splits[2], fen_string_splits[3]),
+            current_gamestate: gamestate,
             gamestate_stack: Vec::with_capacity(50),
+            move_history: Vec::with_capacity(50),
+            castling_rooks,
+            castling_masks: [0; 64],
+            chess960: false,
         };
+        res.update_castling_masks();
+        res.chess960 = res.has_chess960_castling();
+        res.current_gamestate.hash = res.compute_hash();
+        res.update_accumulators();
+
+        res
+    }
+
+    /// Returns the position flipped top to bottom with the colors swapped, so white's pieces on rank 1 become black's
+    /// pieces on rank 8. The evaluation of the mirrored position from the side to move should stay the same
+    pub fn mirrored(&self) -> Board {
+        let pieces_bb = [
+            self.pieces_bb[PieceColor::Black].map(|bb| bb.flip_vertical()),
+            self.pieces_bb[PieceColor::White].map(|bb| bb.flip_vertical()),
+        ];
+        let castling_rooks = [
+            self.castling_rooks[PieceColor::Black].map(|square| square ^ 56),
+            self.castling_rooks[PieceColor::White].map(|square| square ^ 56),
+        ];
+
+        // white's rights are the two low bits and black's the two high ones
+        let castling_rights = self.current_gamestate.get_castling_rights();
+        let gamestate = Gamestate::new(
+            (castling_rights >> 2) | ((castling_rights & 3) << 2),
+            self.current_gamestate.get_enpassant_square().map(|square| square ^ 56),
+            self.current_gamestate.get_halfmove_clock(),
+            self.current_gamestate.get_fullmove_number(),
+        );
+
+        let mut res = Self::from_parts(pieces_bb, !self.color_to_move, castling_rooks, gamestate);
+        res.generate_legal_moves();
+        res
+    }
+
+    /// Returns the position flipped left to right. Castling is not symmetric along the files, so all castling rights are dropped
+    pub fn flipped_horizontally(&self) -> Board {
+        let pieces_bb = self.pieces_bb.map(|bb_ar| bb_ar.map(|bb| bb.flip_horizontal()));
+        let gamestate = Gamestate::new(
+            0,
+            self.current_gamestate.get_enpassant_square().map(|square| square ^ 7),
+            self.current_gamestate.get_halfmove_clock(),
+            self.current_gamestate.get_fullmove_number(),
+        );
+
+        let mut res = Self::from_parts(pieces_bb, self.color_to_move, CONSTS::STANDARD_CASTLING_ROOKS, gamestate);
         res.generate_legal_moves();
+        res
+    }
+
+    pub fn to_position(&self) -> Position {
+        Position {
+            pieces_bb: self.pieces_bb,
+            color_to_move: self.color_to_move,
+            castling_rooks: self.castling_rooks,
+            gamestate: self.current_gamestate,
+        }
+    }
+
+    pub fn from_position(position: &Position) -> Board {
+        let mut res = Self::from_parts(
+            position.pieces_bb,
+            position.color_to_move,
+            position.castling_rooks,
+            position.gamestate,
+        );
+        res.generate_legal_moves();
+        res
+    }
+
+    /// An empty board with white to move, the starting point for setting up a position piece by piece.
+    /// The board is only usable once kings are placed and finish succeeded
+    pub fn empty() -> Board {
+        Self::from_parts(
+            [[0; 6]; 2],
+            PieceColor::White,
+            CONSTS::STANDARD_CASTLING_ROOKS,
+            Gamestate::new(0, None, 0, 1),
+        )
+    }
+
+    /// Places a piece on the square, replacing the piece that stood there
+    pub fn put_piece(&mut self, square: u64, piece: Piece) -> &mut Self {
+        self.remove_piece(square);
+        self.pieces_bb[piece.get_color()][piece.get_type()].set_square(square);
+        self.colors_bb[piece.get_color()].set_square(square);
+        self.piece_matrix[square as usize] = Some(piece);
+        self
+    }
+
+    pub fn remove_piece(&mut self, square: u64) -> &mut Self {
+        if let Some(piece) = self.piece_matrix[square as usize].take() {
+            self.pieces_bb[piece.get_color()][piece.get_type()].toggle_square(square);
+            self.colors_bb[piece.get_color()].toggle_square(square);
+        }
+        self
+    }
+
+    pub fn set_side_to_move(&mut self, color: PieceColor) -> &mut Self {
+        self.color_to_move = color;
+        self
+    }
+
+    /// Takes the 4 bit mask stored in the gamestate, K = 1, Q = 2, k = 4 and q = 8. Like in a FEN string each right
+    /// refers to the outermost rook on its side of the king, which is looked up by finish
+    pub fn set_castling_rights(&mut self, castling_rights: u8) -> &mut Self {
+        self.current_gamestate = Gamestate::new(
+            castling_rights,
+            self.current_gamestate.get_enpassant_square(),
+            self.current_gamestate.get_halfmove_clock(),
+            self.current_gamestate.get_fullmove_number(),
+        );
+        self
+    }
+
+    pub fn set_en_passant(&mut self, enpassant_square: Option<u64>) -> &mut Self {
+        self.current_gamestate.enpassant_square = enpassant_square;
+        self
+    }
+
+    /// Completes a position set up with the methods above: resolves the castling rooks, recomputes the hash,
+    /// validates the position and generates the legal moves. The move history is cleared
+    pub fn finish(&mut self) -> Result<(), ValidationError> {
+        for color in [PieceColor::White, PieceColor::Black] {
+            for side in CastleSide::BOTH {
+                self.castling_rooks[color][side as usize] = fen::outermost_castling_rook(&self.pieces_bb, color, side);
+            }
+        }
+
+        self.complete_setup()
+    }
+
+    /// The part of finish that runs once the castling rooks are known
+    fn complete_setup(&mut self) -> Result<(), ValidationError> {
+        self.gamestate_stack.clear();
+        self.move_history.clear();
+        self.update_castling_masks();
+        self.chess960 = self.has_chess960_castling();
+        self.current_gamestate.hash = self.compute_hash();
+        self.update_accumulators();
+        self.validate()?;
+
+        // a square no pawn can capture on is dropped, as make_move never sets one
+        if let Some(square) = self.current_gamestate.get_enpassant_square() {
+            if !self.can_capture_enpassant(square, self.color_to_move) {
+                self.current_gamestate.enpassant_square = None;
+                self.current_gamestate.hash = self.compute_hash();
+            }
+        }
+        self.generate_legal_moves();
 
+        Ok(())
+    }
+
+    /// Builds the starting position number `index` (0..960) of Chess960, 518 is the standard starting position
+    pub fn chess960(index: u16) -> Board {
+        let mut res = Self::empty();
+        for (file, piece_type) in chess960::back_rank(index).into_iter().enumerate() {
+            let file = file as u64;
+            res.put_piece(file, Piece::new(piece_type, PieceColor::White))
+                .put_piece(file + 8, Piece::new(PieceType::Pawn, PieceColor::White))
+                .put_piece(file + 48, Piece::new(PieceType::Pawn, PieceColor::Black))
+                .put_piece(file + 56, Piece::new(piece_type, PieceColor::Black));
+        }
+        res.set_castling_rights(0b1111);
+        res.finish().expect("Chess960 starting positions are legal");
         res
     }
 
-    fn read_color_to_move(fen_string: &str) -> PieceColor {
-        match String::from(fen_string).chars().nth(0).unwrap() {
-            'w' => PieceColor::White,
-            'b' => PieceColor::Black,
-            _ => panic!("color not found in fen string"),
+    /// The square of each king clears both rights of its color and the square of each castling rook the right tied to it.
+    /// Kings and castling rooks only leave their squares by moving or being captured, so the masks stay valid until
+    /// the rights are gone
+    fn update_castling_masks(&mut self) {
+        self.castling_masks = [0b1111; 64];
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            let mut king_bb = self.pieces_bb[color][PieceType::King];
+            while king_bb != 0 {
+                let king_index = king_bb.bitscan_reset();
+                for side in CastleSide::BOTH {
+                    self.castling_masks[king_index as usize] &= !side.right_bit(color);
+                }
+            }
+
+            for side in CastleSide::BOTH {
+                self.castling_masks[self.castling_rooks[color][side as usize] as usize] &= !side.right_bit(color);
+            }
         }
     }
 
+    /// True when some castling right can't be played the standard way because the king or the rook are not on their
+    /// usual squares. Castles are then encoded as the king capturing its own rook to avoid clashing with king moves
+    fn has_chess960_castling(&self) -> bool {
+        [PieceColor::White, PieceColor::Black].into_iter().any(|color| {
+            let king_index = 4 + 56 * color as u64;
+
+            CastleSide::BOTH.into_iter().any(|side| {
+                self.current_gamestate.can_castle(color, side)
+                    && (!self.pieces_bb[color][PieceType::King].contains_index(king_index)
+                        || self.castling_rooks[color][side as usize] != CONSTS::STANDARD_CASTLING_ROOKS[color][side as usize])
+            })
+        })
+    }
+
+    #[inline]
+    pub fn is_chess960(&self) -> bool {
+        self.chess960
+    }
+
+    #[inline]
+    pub fn get_castling_rook(&self, color: PieceColor, side: CastleSide) -> u64 {
+        self.castling_rooks[color][side as usize]
+    }
+
+    pub fn to_fen(&self) -> String {
+        let color_to_move = match self.color_to_move {
+            PieceColor::White => 'w',
+            PieceColor::Black => 'b',
+        };
+
+        format!(
+            "{} {} {} {} {} {}",
+            fen::write_piece_placement(&self.piece_matrix),
+            color_to_move,
+            self.castling_rights_string(),
+            fen::write_enpassant_square(self.current_gamestate.get_enpassant_square()),
+            self.current_gamestate.get_halfmove_clock(),
+            self.current_gamestate.get_fullmove_number(),
+        )
+    }
+
+    fn castling_rights_string(&self) -> String {
+        fen::write_castling_rights(
+            self.current_gamestate.get_castling_rights(),
+            &self.castling_rooks,
+            &self.pieces_bb,
+        )
+    }
+
+    /// Every piece bitboard side by side, a row of white boards followed by a row of black boards each ending with
+    /// the color bitboard. Meant to be pasted into bug reports next to the Display output
+    pub fn bitboards_dump(&self) -> String {
+        let mut res = String::new();
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            let mut boards: Vec<(String, u64)> = (0..6usize)
+                .map(|piece_index| {
+                    let piece_type = PieceType::from(piece_index);
+                    (format!("{color:?} {piece_type:?}"), self.pieces_bb[color][piece_type])
+                })
+                .collect();
+            boards.push((format!("{color:?} pieces"), self.colors_bb[color]));
+
+            let rows: Vec<[String; 8]> = boards.iter().map(|&(_, bb)| bitboard_rows(bb)).collect();
+
+            let titles: String = boards.iter().map(|(title, _)| format!("{title:<18}")).collect();
+            res.push_str(titles.trim_end());
+            res.push('\n');
+            for row_index in 0..8 {
+                let line: String = rows.iter().map(|rows| format!("{:<18}", rows[row_index])).collect();
+                res.push_str(line.trim_end());
+                res.push('\n');
+            }
+            res.push('\n');
+        }
+
+        res
+    }
+
+    #[inline]
+    pub fn get_hash(&self) -> u64 {
+        self.current_gamestate.get_hash()
+    }
+
+    /// Computes the zobrist key of the position from scratch, make_move keeps it updated incrementally
+    pub fn compute_hash(&self) -> u64 {
+        let mut hash = 0u64;
+
+        for (index, opt_piece) in self.piece_matrix.iter().enumerate() {
+            if let Some(piece) = opt_piece {
+                hash ^= ZOBRIST.piece(*piece, index as u64);
+            }
+        }
+
+        if self.color_to_move == PieceColor::Black {
+            hash ^= ZOBRIST.black_to_move();
+        }
+
+        hash ^ ZOBRIST.castling_rights(self.current_gamestate.get_castling_rights())
+            ^ ZOBRIST.enpassant_square(self.current_gamestate.get_enpassant_square())
+    }
+
+    /// Cross-checks the bitboards, the piece matrix and the gamestate and returns the first inconsistency found.
+    /// Debug builds run it after every make and unmake
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut occupied_bb = 0u64;
+        for color in [PieceColor::White, PieceColor::Black] {
+            let mut color_bb = 0u64;
+            for bb in self.pieces_bb[color] {
+                if occupied_bb & bb != 0 {
+                    return Err(ValidationError::OverlappingPieces {
+                        square: (occupied_bb & bb).bitscan(),
+                    });
+                }
+                occupied_bb |= bb;
+                color_bb |= bb;
+            }
+
+            if color_bb != self.colors_bb[color] {
+                return Err(ValidationError::ColorBitboardMismatch { color });
+            }
+        }
+
+        for (index, opt_piece) in self.piece_matrix.iter().enumerate() {
+            let index = index as u64;
+            let matches_bitboards = match opt_piece {
+                Some(piece) => self.pieces_bb[piece.get_color()][piece.get_type()].contains_index(index),
+                None => !occupied_bb.contains_index(index),
+            };
+            if !matches_bitboards {
+                return Err(ValidationError::PieceMatrixMismatch { square: index });
+            }
+        }
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            let count = self.pieces_bb[color][PieceType::King].count_ones();
+            if count != 1 {
+                return Err(ValidationError::KingCount { color, count });
+            }
+
+            let count = self.pieces_bb[color][PieceType::Pawn].count_ones();
+            if count > 8 {
+                return Err(ValidationError::PawnCount { color, count });
+            }
+
+            let count = self.colors_bb[color].count_ones();
+            if count > 16 {
+                return Err(ValidationError::PieceCount { color, count });
+            }
+        }
+
+        let pawns_bb = self.pieces_bb[PieceColor::White][PieceType::Pawn] | self.pieces_bb[PieceColor::Black][PieceType::Pawn];
+        if pawns_bb & CONSTS::BACK_RANKS != 0 {
+            return Err(ValidationError::PawnOnBackRank {
+                square: (pawns_bb & CONSTS::BACK_RANKS).bitscan(),
+            });
+        }
+
+        let enemy_king_index = self.pieces_bb[!self.color_to_move][PieceType::King].bitscan();
+        if self.is_square_attacked(enemy_king_index, self.color_to_move) {
+            return Err(ValidationError::OpponentInCheck);
+        }
+
+        // the square must be empty with the pawn that just made a double push right in front of it
+        if let Some(square) = self.current_gamestate.get_enpassant_square() {
+            let valid = match self.color_to_move {
+                PieceColor::White => {
+                    square / 8 == 5 && self.pieces_bb[PieceColor::Black][PieceType::Pawn].contains_index(square - 8)
+                }
+                PieceColor::Black => {
+                    square / 8 == 2 && self.pieces_bb[PieceColor::White][PieceType::Pawn].contains_index(square + 8)
+                }
+            };
+            if !valid || self.piece_matrix[square as usize].is_some() {
+                return Err(ValidationError::EnpassantSquare { square });
+            }
+        }
+
+        for color in [PieceColor::White, PieceColor::Black] {
+            let king_index = self.pieces_bb[color][PieceType::King].bitscan();
+            let back_rank = 7 * color as u64;
+
+            for side in CastleSide::BOTH {
+                if !self.current_gamestate.can_castle(color, side) {
+                    continue;
+                }
+
+                let rook_index = self.castling_rooks[color][side as usize];
+                let king_in_place = match self.chess960 {
+                    true => king_index / 8 == back_rank,
+                    false => king_index == back_rank * 8 + 4,
+                };
+                let rook_in_place = self.pieces_bb[color][PieceType::Rook].contains_index(rook_index)
+                    && match side {
+                        CastleSide::Kingside => rook_index > king_index,
+                        CastleSide::Queenside => rook_index < king_index,
+                    };
+
+                if !king_in_place || !rook_in_place {
+                    return Err(ValidationError::CastlingRights { color, side });
+                }
+            }
+        }
+
+        if self.compute_scores()
+            != (
+                self.current_gamestate.material,
+                self.current_gamestate.psqt,
+                self.current_gamestate.phase,
+            )
+        {
+            return Err(ValidationError::ScoreMismatch);
+        }
+        if self.compute_material_signature() != self.current_gamestate.get_material_signature() {
+            return Err(ValidationError::MaterialSignatureMismatch);
+        }
+        if self.compute_pawn_hash() != self.current_gamestate.get_pawn_hash() {
+            return Err(ValidationError::PawnHashMismatch);
+        }
+
+        let computed = self.compute_hash();
+        if computed != self.get_hash() {
+            return Err(ValidationError::HashMismatch {
+                stored: self.get_hash(),
+                computed,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Computes the material, piece-square and phase accumulators from scratch, make_move keeps them updated incrementally
+    pub fn compute_scores(&self) -> ([Score; 2], [Score; 2], u8) {
+        let mut material = [Score::ZERO; 2];
+        let mut psqt = [Score::ZERO; 2];
+        let mut phase = 0;
+
+        for (index, opt_piece) in self.piece_matrix.iter().enumerate() {
+            if let Some(piece) = opt_piece {
+                material[piece.get_color()] += psqt::material(piece.get_type());
+                psqt[piece.get_color()] += psqt::psqt(*piece, index as u64);
+                phase += psqt::phase(piece.get_type());
+            }
+        }
+
+        (material, psqt, phase)
+    }
+
+    pub fn compute_material_signature(&self) -> MaterialSignature {
+        let mut signature = MaterialSignature::EMPTY;
+        for piece in self.piece_matrix.iter().flatten() {
+            signature.add(*piece);
+        }
+        signature
+    }
+
+    /// Zobrist key of the pawns alone, positions with the same pawn structure share it
+    pub fn compute_pawn_hash(&self) -> u64 {
+        let mut hash = 0u64;
+        for (index, opt_piece) in self.piece_matrix.iter().enumerate() {
+            if let Some(piece) = opt_piece.filter(|piece| piece.get_type() == PieceType::Pawn) {
+                hash ^= ZOBRIST.piece(piece, index as u64);
+            }
+        }
+        hash
+    }
+
+    /// Sets every value kept up to date by make_move apart from the full hash
+    fn update_accumulators(&mut self) {
+        let (material, psqt, phase) = self.compute_scores();
+        self.current_gamestate.material = material;
+        self.current_gamestate.psqt = psqt;
+        self.current_gamestate.phase = phase;
+        self.current_gamestate.material_signature = self.compute_material_signature();
+        self.current_gamestate.pawn_hash = self.compute_pawn_hash();
+    }
+
+    #[inline]
+    fn track_piece_added(&mut self, piece: Piece, square: u64) {
+        let gamestate = &mut self.current_gamestate;
+        gamestate.material[piece.get_color()] += psqt::material(piece.get_type());
+        gamestate.psqt[piece.get_color()] += psqt::psqt(piece, square);
+        gamestate.phase += psqt::phase(piece.get_type());
+        gamestate.material_signature.add(piece);
+        if piece.get_type() == PieceType::Pawn {
+            gamestate.pawn_hash ^= ZOBRIST.piece(piece, square);
+        }
+    }
+
+    #[inline]
+    fn track_piece_removed(&mut self, piece: Piece, square: u64) {
+        let gamestate = &mut self.current_gamestate;
+        gamestate.material[piece.get_color()] -= psqt::material(piece.get_type());
+        gamestate.psqt[piece.get_color()] -= psqt::psqt(piece, square);
+        gamestate.phase -= psqt::phase(piece.get_type());
+        gamestate.material_signature.remove(piece);
+        if piece.get_type() == PieceType::Pawn {
+            gamestate.pawn_hash ^= ZOBRIST.piece(piece, square);
+        }
+    }
+
+    #[inline]
+    pub fn get_material_signature(&self) -> MaterialSignature {
+        self.current_gamestate.get_material_signature()
+    }
+
+    #[inline]
+    pub fn get_pawn_hash(&self) -> u64 {
+        self.current_gamestate.get_pawn_hash()
+    }
+
+    /// Material plus piece-square score of the side to move minus the opponent's, blended by the game phase
+    #[inline]
+    pub fn get_tapered_score(&self) -> i32 {
+        let us = self.color_to_move;
+        let gamestate = self.current_gamestate;
+        let score = gamestate.get_material(us) + gamestate.get_psqt(us) - gamestate.get_material(!us) - gamestate.get_psqt(!us);
+
+        score.taper(gamestate.get_phase())
+    }
+
     #[inline]
     pub fn get_pieces_bb(&self) -> [[u64; 6]; 2] {
         self.pieces_bb
@@ -112,42 +663,55 @@ impl Board {
     /// THIS METHOD CHANGES COLOR_TO_MOVE
     pub fn make_move(&mut self, move_to_make: Move) {
         let start_index = move_to_make.get_from();
-        let land_index = move_to_make.get_to();
         let moved_piece = move_to_make.get_moved_piece();
         let moved_color = moved_piece.get_color();
         let moved_type = moved_piece.get_type();
         let enemy_color = !moved_piece.get_color();
+        let opt_castle_side = move_to_make.get_castle_side();
+        let land_index = match opt_castle_side {
+            Some(side) => CONSTS::CASTLING_KING_DESTINATION[moved_color][side as usize],
+            None => move_to_make.get_to(),
+        };
 
         self.gamestate_stack.push(self.current_gamestate); // push old gamestate
+        self.move_history.push(move_to_make);
+
+        // the old castling rights and en passant square are hashed out here and the new ones hashed in at the end
+        let mut hash = self.current_gamestate.hash
+            ^ ZOBRIST.castling_rights(self.current_gamestate.get_castling_rights())
+            ^ ZOBRIST.enpassant_square(self.current_gamestate.get_enpassant_square());
 
         self.current_gamestate.last_piece_captured = None;
         self.current_gamestate.enpassant_square = None;
 
         self.pieces_bb[moved_color][moved_type].toggle_squares(start_index, land_index);
         self.colors_bb[moved_color].toggle_squares(start_index, land_index);
+        hash ^= ZOBRIST.piece(moved_piece, start_index) ^ ZOBRIST.piece(moved_piece, land_index);
+        self.track_piece_removed(moved_piece, start_index);
+        self.track_piece_added(moved_piece, land_index);
 
-        // change castling rights
-        if moved_type == PieceType::King {
-            self.current_gamestate.remove_castle_kingside(moved_color);
-            self.current_gamestate.remove_castle_queenside(moved_color);
-        } else if moved_type == PieceType::Rook {
-            if start_index == 0 || start_index == 56 {
-                self.current_gamestate.remove_castle_queenside(moved_color);
-            } else if start_index == 7 || start_index == 63 {
-                self.current_gamestate.remove_castle_kingside(moved_color);
-            }
-        } else if move_to_make.is_promotion() {
+        // moving the king or a castling rook, or capturing the rook, loses the rights tied to them
+        self.current_gamestate
+            .retain_castling_rights(self.castling_masks[start_index as usize] & self.castling_masks[land_index as usize]);
+
+        if move_to_make.is_promotion() {
             let promotion_type = move_to_make.get_promotion_type();
             self.pieces_bb[moved_color][moved_type].toggle_square(land_index);
             self.pieces_bb[moved_color][promotion_type].toggle_square(land_index);
             self.piece_matrix[start_index as usize] = Some(Piece::new(promotion_type, moved_color));
+            hash ^= ZOBRIST.piece(moved_piece, land_index) ^ ZOBRIST.piece(Piece::new(promotion_type, moved_color), land_index);
+            self.track_piece_removed(moved_piece, land_index);
+            self.track_piece_added(Piece::new(promotion_type, moved_color), land_index);
         }
 
-        // double pawn push so change en passant target square
+        // double pawn push so change en passant target square, only when an enemy pawn can capture on it
         if start_index.abs_diff(land_index) == 16 && moved_type == PieceType::Pawn {
-            match moved_color {
-                PieceColor::White => self.current_gamestate.enpassant_square = Some(start_index + 8),
-                PieceColor::Black => self.current_gamestate.enpassant_square = Some(start_index - 8),
+            let enpassant_index = match moved_color {
+                PieceColor::White => start_index + 8,
+                PieceColor::Black => start_index - 8,
+            };
+            if self.can_capture_enpassant(enpassant_index, enemy_color) {
+                self.current_gamestate.enpassant_square = Some(enpassant_index);
             }
         }
         // en passant
@@ -161,42 +725,73 @@ impl Board {
             self.colors_bb[enemy_color].toggle_square(enemy_pawn_index);
             self.piece_matrix[enemy_pawn_index as usize] = None;
             self.current_gamestate.last_piece_captured = Some(Piece::new(PieceType::Pawn, enemy_color));
+            hash ^= ZOBRIST.piece(Piece::new(PieceType::Pawn, enemy_color), enemy_pawn_index);
+            self.track_piece_removed(Piece::new(PieceType::Pawn, enemy_color), enemy_pawn_index);
+        }
+        // castle, checked before captures because in Chess960 the king can land on the square of its own rook
+        else if let Some(side) = opt_castle_side {
+            let (rook_from, rook_to) = self.get_castling_rook_squares(moved_color, side);
+
+            self.pieces_bb[moved_color][PieceType::Rook].toggle_squares(rook_from, rook_to);
+            self.colors_bb[moved_color].toggle_squares(rook_from, rook_to);
+            hash ^= ZOBRIST.piece(Piece::new(PieceType::Rook, moved_color), rook_from)
+                ^ ZOBRIST.piece(Piece::new(PieceType::Rook, moved_color), rook_to);
+            self.track_piece_removed(Piece::new(PieceType::Rook, moved_color), rook_from);
+            self.track_piece_added(Piece::new(PieceType::Rook, moved_color), rook_to);
         }
         // capture
         else if let Some(captured_piece) = self.get_piece_at(land_index) {
             self.pieces_bb[enemy_color][captured_piece.get_type()].toggle_square(land_index);
             self.colors_bb[enemy_color].toggle_square(land_index);
             self.current_gamestate.last_piece_captured = Some(captured_piece);
-        } else if move_to_make.is_castle_kingside() {
-            let rook_from = start_index + 3;
-            let rook_to = start_index + 1;
-
-            self.pieces_bb[moved_color][PieceType::Rook].toggle_squares(rook_from, rook_to);
-            self.colors_bb[moved_color].toggle_squares(rook_from, rook_to);
-            self.piece_matrix[rook_to as usize] = self.piece_matrix[rook_from as usize].take();
-        } else if move_to_make.is_castle_queenside() {
-            let rook_from = start_index - 4;
-            let rook_to = start_index - 1;
+            hash ^= ZOBRIST.piece(captured_piece, land_index);
+            self.track_piece_removed(captured_piece, land_index);
+        }
 
-            self.pieces_bb[moved_color][PieceType::Rook].toggle_squares(rook_from, rook_to);
-            self.colors_bb[moved_color].toggle_squares(rook_from, rook_to);
-            self.piece_matrix[rook_to as usize] = self.piece_matrix[rook_from as usize].take();
+        // update move counters, they saturate since a FEN can start them anywhere up to u16::MAX
+        if moved_type == PieceType::Pawn || self.current_gamestate.last_piece_captured.is_some() {
+            self.current_gamestate.halfmove_clock = 0;
+        } else {
+            self.current_gamestate.halfmove_clock = self.current_gamestate.halfmove_clock.saturating_add(1);
         }
+        if moved_color == PieceColor::Black {
+            self.current_gamestate.fullmove_number = self.current_gamestate.fullmove_number.saturating_add(1);
+        }
+        self.current_gamestate.plies_from_null = self.current_gamestate.plies_from_null.saturating_add(1);
+
+        self.current_gamestate.hash = hash
+            ^ ZOBRIST.castling_rights(self.current_gamestate.get_castling_rights())
+            ^ ZOBRIST.enpassant_square(self.current_gamestate.get_enpassant_square())
+            ^ ZOBRIST.black_to_move();
 
-        self.piece_matrix[land_index as usize] = self.piece_matrix[start_index as usize].take();
+        match opt_castle_side {
+            Some(side) => {
+                let (rook_from, rook_to) = self.get_castling_rook_squares(moved_color, side);
+                self.move_castling_pieces(start_index, land_index, rook_from, rook_to);
+            }
+            None => self.piece_matrix[land_index as usize] = self.piece_matrix[start_index as usize].take(),
+        }
         self.color_to_move = !self.color_to_move;
+
+        debug_assert_eq!(self.validate(), Ok(()), "after making {move_to_make:?}\n{self}\n{}", self.bitboards_dump());
     }
 
     /// THIS METHOD CHANGES COLOR_TO_MOVE
     pub fn unmake_move(&mut self, move_to_unmake: Move) {
         let start_index = move_to_unmake.get_from();
-        let land_index = move_to_unmake.get_to();
         let moved_piece = move_to_unmake.get_moved_piece();
         let moved_color = moved_piece.get_color();
+        let opt_castle_side = move_to_unmake.get_castle_side();
+        let land_index = match opt_castle_side {
+            Some(side) => CONSTS::CASTLING_KING_DESTINATION[moved_color][side as usize],
+            None => move_to_unmake.get_to(),
+        };
 
         self.pieces_bb[moved_color][moved_piece.get_type()].toggle_squares(start_index, land_index);
         self.colors_bb[moved_color].toggle_squares(start_index, land_index);
-        self.piece_matrix[start_index as usize] = self.piece_matrix[land_index as usize].take();
+        if opt_castle_side.is_none() {
+            self.piece_matrix[start_index as usize] = self.piece_matrix[land_index as usize].take();
+        }
 
         if move_to_unmake.is_promotion() {
             self.pieces_bb[moved_color][move_to_unmake.get_promotion_type()].toggle_square(land_index);
@@ -219,23 +814,234 @@ impl Board {
             self.pieces_bb[enemy_color][captured_piece.get_type()].toggle_square(land_index);
             self.colors_bb[enemy_color].toggle_square(land_index);
             self.piece_matrix[land_index as usize] = self.current_gamestate.get_last_piece_captured();
-        } else if move_to_unmake.is_castle_kingside() {
-            let rook_from = start_index + 3;
-            let rook_to = start_index + 1;
+        } else if let Some(side) = opt_castle_side {
+            let (rook_from, rook_to) = self.get_castling_rook_squares(moved_color, side);
 
             self.pieces_bb[moved_color][PieceType::Rook].toggle_squares(rook_from, rook_to);
             self.colors_bb[moved_color].toggle_squares(rook_from, rook_to);
-            self.piece_matrix[rook_from as usize] = self.piece_matrix[rook_to as usize].take();
-        } else if move_to_unmake.is_castle_queenside() {
-            let rook_from = start_index - 4;
-            let rook_to = start_index - 1;
+            self.move_castling_pieces(land_index, start_index, rook_to, rook_from);
+        }
+        self.current_gamestate = self.gamestate_stack.pop().unwrap();
+        self.move_history.pop();
+        self.color_to_move = !self.color_to_move;
 
-            self.pieces_bb[moved_color][PieceType::Rook].toggle_squares(rook_from, rook_to);
-            self.colors_bb[moved_color].toggle_squares(rook_from, rook_to);
-            self.piece_matrix[rook_from as usize] = self.piece_matrix[rook_to as usize].take();
+        debug_assert_eq!(self.validate(), Ok(()), "after unmaking {move_to_unmake:?}\n{self}\n{}", self.bitboards_dump());
+    }
+
+    /// Takes back the last played move and returns it, None when there is no move to take back or a null move
+    /// was played after it. The legal moves are not regenerated, same as after make_move
+    pub fn undo(&mut self) -> Option<Move> {
+        // null moves push a gamestate without a move, so the stacks only line up when none is played
+        if self.gamestate_stack.len() != self.move_history.len() {
+            return None;
         }
+        let last_move = self.last_move()?;
+        self.unmake_move(last_move);
+        Some(last_move)
+    }
+
+    #[inline]
+    pub fn last_move(&self) -> Option<Move> {
+        self.move_history.last().copied()
+    }
+
+    /// The moves played since the board was set up, oldest first
+    #[inline]
+    pub fn history(&self) -> &[Move] {
+        &self.move_history
+    }
+
+    /// Passes the turn without moving a piece, only used by the search. Bitboards are untouched,
+    /// the en passant square is cleared and the old gamestate is pushed like a normal move.
+    /// The side to move must not be in check, passing would leave its king capturable
+    pub fn make_null_move(&mut self) {
+        debug_assert!(!self.is_in_check(), "null move played while in check in {}", self.to_fen());
+
+        self.gamestate_stack.push(self.current_gamestate);
+
+        self.current_gamestate.hash ^=
+            ZOBRIST.enpassant_square(self.current_gamestate.get_enpassant_square()) ^ ZOBRIST.black_to_move();
+        self.current_gamestate.last_piece_captured = None;
+        self.current_gamestate.enpassant_square = None;
+        self.current_gamestate.plies_from_null = 0;
+
+        self.color_to_move = !self.color_to_move;
+
+        debug_assert_eq!(self.validate(), Ok(()), "after making a null move\n{self}\n{}", self.bitboards_dump());
+    }
+
+    pub fn unmake_null_move(&mut self) {
         self.current_gamestate = self.gamestate_stack.pop().unwrap();
         self.color_to_move = !self.color_to_move;
+
+        debug_assert_eq!(self.validate(), Ok(()), "after unmaking a null move\n{self}\n{}", self.bitboards_dump());
+    }
+
+    /// Returns the starting and landing square of the rook when castling on the given side
+    #[inline]
+    fn get_castling_rook_squares(&self, color: PieceColor, side: CastleSide) -> (u64, u64) {
+        (
+            self.castling_rooks[color][side as usize],
+            CONSTS::CASTLING_ROOK_DESTINATION[color][side as usize],
+        )
+    }
+
+    /// In Chess960 the squares of king and rook can overlap before and after castling,
+    /// so both pieces are lifted from the matrix before being placed again
+    fn move_castling_pieces(&mut self, king_from: u64, king_to: u64, rook_from: u64, rook_to: u64) {
+        let king = self.piece_matrix[king_from as usize].take();
+        let rook = self.piece_matrix[rook_from as usize].take();
+
+        self.piece_matrix[king_to as usize] = king;
+        self.piece_matrix[rook_to as usize] = rook;
+    }
+
+    /// Either player can claim a draw once 50 moves were played without captures or pawn moves
+    #[inline]
+    pub fn is_fifty_move_draw(&self) -> bool {
+        self.current_gamestate.get_halfmove_clock() >= 100
+    }
+
+    /// After 75 moves without captures or pawn moves the game is drawn without any claim
+    #[inline]
+    pub fn is_seventy_five_move_draw(&self) -> bool {
+        self.current_gamestate.get_halfmove_clock() >= 150
+    }
+
+    /// How many times the current position occurred since the last capture or pawn move, the current occurrence included.
+    /// The gamestate stack doubles as the position history since every gamestate stores the hash of its position
+    pub fn repetition_count(&self) -> usize {
+        let hash = self.current_gamestate.get_hash();
+        // positions before a null move are not reachable in a real game
+        let reversible_plies = self
+            .current_gamestate
+            .get_halfmove_clock()
+            .min(self.current_gamestate.get_plies_from_null()) as usize;
+
+        // only positions with the same color to move can be equal, so every other ply is skipped
+        1 + self
+            .gamestate_stack
+            .iter()
+            .rev()
+            .take(reversible_plies)
+            .skip(1)
+            .step_by(2)
+            .filter(|gamestate| gamestate.get_hash() == hash)
+            .count()
+    }
+
+    #[inline]
+    pub fn is_repetition(&self) -> bool {
+        self.repetition_count() >= 2
+    }
+
+    #[inline]
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.repetition_count() >= 3
+    }
+
+    #[inline]
+    pub fn is_fivefold_repetition(&self) -> bool {
+        self.repetition_count() >= 5
+    }
+
+    #[inline]
+    pub fn is_in_check(&self) -> bool {
+        let king_index = self.pieces_bb[self.color_to_move][PieceType::King].trailing_zeros() as u64;
+        self.is_square_attacked(king_index, !self.color_to_move)
+    }
+
+    #[inline]
+    pub fn attackers_to(&self, square: u64, attacker_color: PieceColor) -> u64 {
+        move_generation::attackers_to(self, square, attacker_color)
+    }
+
+    #[inline]
+    pub fn is_square_attacked(&self, square: u64, by: PieceColor) -> bool {
+        self.attackers_to(square, by) != 0
+    }
+
+    /// Whether a pawn of the given color stands next to the pawn that just passed over the en passant square.
+    /// Like Polyglot the square is only kept then, so that positions differing in nothing else hash and repeat alike.
+    /// Pins are not considered
+    fn can_capture_enpassant(&self, enpassant_index: u64, capturing_color: PieceColor) -> bool {
+        self.attackers_to(enpassant_index, capturing_color) & self.pieces_bb[capturing_color][PieceType::Pawn] != 0
+    }
+
+    /// Material won by the side playing m once the captures on its target square are resolved, see move_generation::see
+    #[inline]
+    pub fn see(&self, m: Move) -> i32 {
+        move_generation::see(self, m)
+    }
+
+    #[inline]
+    pub fn see_ge(&self, m: Move, threshold: i32) -> bool {
+        move_generation::see_ge(self, m, threshold)
+    }
+
+    /// Pieces of the given color that can't leave the line between their king and an enemy slider
+    #[inline]
+    pub fn pinned_pieces(&self, color: PieceColor) -> u64 {
+        move_generation::pinned_pieces(self, color)
+    }
+
+    /// True when no sequence of legal moves can lead to a checkmate: K vs K, K+minor vs K and any number of bishops
+    /// that all stand on squares of the same color
+    pub fn has_insufficient_material(&self) -> bool {
+        // a bare king against a king with at most one minor piece, whichever color has it
+        let lone_minor_keys = [
+            MaterialSignature::KINGS,
+            MaterialSignature::KINGS.with(PieceColor::White, PieceType::Bishop),
+            MaterialSignature::KINGS.with(PieceColor::White, PieceType::Knight),
+        ]
+        .map(MaterialSignature::key);
+        let signature = self.get_material_signature();
+        if lone_minor_keys.contains(&signature.key()) || lone_minor_keys.contains(&signature.mirrored().key()) {
+            return true;
+        }
+
+        let [white_pieces_bb, black_pieces_bb] = self.pieces_bb;
+        let all_of_type = |piece_type: PieceType| white_pieces_bb[piece_type] | black_pieces_bb[piece_type];
+
+        if all_of_type(PieceType::Pawn) | all_of_type(PieceType::Rook) | all_of_type(PieceType::Queen) != 0 {
+            return false;
+        }
+
+        let knights_bb = all_of_type(PieceType::Knight);
+        let bishops_bb = all_of_type(PieceType::Bishop);
+
+        knights_bb == 0 && (bishops_bb & CONSTS::LIGHT_SQUARES == 0 || bishops_bb & !CONSTS::LIGHT_SQUARES == 0)
+    }
+
+    pub fn status(&self) -> GameStatus {
+        if generate_legal_moves(self).len() == 0 {
+            return if self.is_in_check() {
+                GameStatus::Checkmate(!self.color_to_move)
+            } else {
+                GameStatus::Stalemate
+            };
+        }
+
+        if self.has_insufficient_material() {
+            GameStatus::InsufficientMaterial
+        } else if self.is_seventy_five_move_draw() {
+            GameStatus::SeventyFiveMove
+        } else if self.is_fivefold_repetition() {
+            GameStatus::FivefoldRepetition
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+
+    /// The draw the side to move can claim in a game that isn't over yet, the game only ends once it is claimed
+    pub fn claimable_draw(&self) -> Option<GameStatus> {
+        if self.is_fifty_move_draw() {
+            Some(GameStatus::FiftyMove)
+        } else if self.is_threefold_repetition() {
+            Some(GameStatus::ThreefoldRepetition)
+        } else {
+            None
+        }
     }
 
     #[inline]
@@ -262,3 +1068,63 @@ impl Board {
         )
     }
 }
+
+#[cfg(test)]
+impl Board {
+    /// The representations validate compares with each other, for tests that need them out of sync
+    pub fn raw_parts_mut(&mut self) -> (&mut [[u64; 6]; 2], &mut [u64; 2], &mut [Option<Piece>; 64]) {
+        (&mut self.pieces_bb, &mut self.colors_bb, &mut self.piece_matrix)
+    }
+}
+
+/// Prints the board with rank 8 on top followed by the rest of the gamestate, the alternate flag `{:#}`
+/// draws Unicode figurines instead of FEN letters
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  +-----------------+")?;
+        for rank in (0..8).rev() {
+            write!(f, "{} |", rank + 1)?;
+            for file in 0..8 {
+                let c = match self.piece_matrix[rank * 8 + file] {
+                    Some(piece) if f.alternate() => piece_to_figurine(piece),
+                    Some(piece) => fen::piece_to_char(piece),
+                    None => '.',
+                };
+                write!(f, " {c}")?;
+            }
+            writeln!(f, " |")?;
+        }
+        writeln!(f, "  +-----------------+")?;
+        writeln!(f, "    a b c d e f g h")?;
+        writeln!(f)?;
+
+        writeln!(f, "Side to move: {:?}", self.color_to_move)?;
+        writeln!(f, "Castling:     {}", self.castling_rights_string())?;
+        writeln!(
+            f,
+            "En passant:   {}",
+            fen::write_enpassant_square(self.current_gamestate.get_enpassant_square())
+        )?;
+        writeln!(f, "Material:     {}", self.get_material_signature())?;
+        writeln!(f, "FEN:          {}", self.to_fen())?;
+        writeln!(f, "Hash:         {:#018x}", self.get_hash())?;
+        write!(f, "Pawn key:     {:#018x}", self.get_pawn_hash())
+    }
+}
+
+fn piece_to_figurine(piece: Piece) -> char {
+    match (piece.get_color(), piece.get_type()) {
+        (PieceColor::White, PieceType::King) => '\u{2654}',
+        (PieceColor::White, PieceType::Queen) => '\u{2655}',
+        (PieceColor::White, PieceType::Rook) => '\u{2656}',
+        (PieceColor::White, PieceType::Bishop) => '\u{2657}',
+        (PieceColor::White, PieceType::Knight) => '\u{2658}',
+        (PieceColor::White, PieceType::Pawn) => '\u{2659}',
+        (PieceColor::Black, PieceType::King) => '\u{265A}',
+        (PieceColor::Black, PieceType::Queen) => '\u{265B}',
+        (PieceColor::Black, PieceType::Rook) => '\u{265C}',
+        (PieceColor::Black, PieceType::Bishop) => '\u{265D}',
+        (PieceColor::Black, PieceType::Knight) => '\u{265E}',
+        (PieceColor::Black, PieceType::Pawn) => '\u{265F}',
+    }
+}
@@ -0,0 +1,48 @@
+use crate::piece::PieceType;
+
+// pairs of free squares taken by the knights, in the order of the Scharnagl numbering
+const KNIGHT_PLACEMENTS: [(usize, usize); 10] = [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)];
+
+/// Returns the back rank pieces from file a to file h of the Chess960 starting position with the given Scharnagl
+/// number (0..960). Number 518 is the standard starting position
+pub fn back_rank(index: u16) -> [PieceType; 8] {
+    if index >= 960 {
+        panic!("Chess960 position index out of range: {index}");
+    }
+
+    let mut back_rank: [Option<PieceType>; 8] = [None; 8];
+    let mut n = index as usize;
+
+    back_rank[(n % 4) * 2 + 1] = Some(PieceType::Bishop); // light squared bishop
+    n /= 4;
+    back_rank[(n % 4) * 2] = Some(PieceType::Bishop); // dark squared bishop
+    n /= 4;
+
+    let queen_square = nth_free_square(&back_rank, n % 6);
+    back_rank[queen_square] = Some(PieceType::Queen);
+    n /= 6;
+
+    let (first_knight, second_knight) = KNIGHT_PLACEMENTS[n];
+    let knight_squares = [nth_free_square(&back_rank, first_knight), nth_free_square(&back_rank, second_knight)];
+    for square in knight_squares {
+        back_rank[square] = Some(PieceType::Knight);
+    }
+
+    // the king always ends up between the two rooks
+    for piece_type in [PieceType::Rook, PieceType::King, PieceType::Rook] {
+        let square = nth_free_square(&back_rank, 0);
+        back_rank[square] = Some(piece_type);
+    }
+
+    back_rank.map(|piece_type| piece_type.unwrap())
+}
+
+fn nth_free_square(back_rank: &[Option<PieceType>; 8], n: usize) -> usize {
+    back_rank
+        .iter()
+        .enumerate()
+        .filter(|(_, square)| square.is_none())
+        .nth(n)
+        .map(|(file, _)| file)
+        .unwrap()
+}
@@ -1,4 +1,4 @@
-use crate::{Piece, PieceType};
+use crate::{gamestate::CastleSide, Piece, PieceType};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Move {
@@ -60,6 +60,25 @@ impl Move {
         !self.is_promotion() && (self.move_code >> 15) & 1 == 1
     }
     
+    #[inline]
+    pub fn add_castle(&mut self, side: CastleSide) {
+        match side {
+            CastleSide::Kingside => self.add_castle_kingside(),
+            CastleSide::Queenside => self.add_castle_queenside(),
+        }
+    }
+
+    #[inline]
+    pub fn get_castle_side(self) -> Option<CastleSide> {
+        if self.is_castle_kingside() {
+            Some(CastleSide::Kingside)
+        } else if self.is_castle_queenside() {
+            Some(CastleSide::Queenside)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     pub fn add_promotion(&mut self, piece_to_promote_to: PieceType) {
         self.move_code += 4096;
@@ -1,138 +1,7 @@
 #[allow(non_snake_case)]
 pub mod CONSTS {
-    pub const KNIGHT_TABLE: [u64; 64] = [
-        0x0000000000020400,
-        0x0000000000050800,
-        0x00000000000a1100,
-        0x0000000000142200,
-        0x0000000000284400,
-        0x0000000000508800,
-        0x0000000000a01000,
-        0x0000000000402000,
-        0x0000000002040004,
-        0x0000000005080008,
-        0x000000000a110011,
-        0x0000000014220022,
-        0x0000000028440044,
-        0x0000000050880088,
-        0x00000000a0100010,
-        0x0000000040200020,
-        0x0000000204000402,
-        0x0000000508000805,
-        0x0000000a1100110a,
-        0x0000001422002214,
-        0x0000002844004428,
-        0x0000005088008850,
-        0x000000a0100010a0,
-        0x0000004020002040,
-        0x0000020400040200,
-        0x0000050800080500,
-        0x00000a1100110a00,
-        0x0000142200221400,
-        0x0000284400442800,
-        0x0000508800885000,
-        0x0000a0100010a000,
-        0x0000402000204000,
-        0x0002040004020000,
-        0x0005080008050000,
-        0x000a1100110a0000,
-        0x0014220022140000,
-        0x0028440044280000,
-        0x0050880088500000,
-        0x00a0100010a00000,
-        0x0040200020400000,
-        0x0204000402000000,
-        0x0508000805000000,
-        0x0a1100110a000000,
-        0x1422002214000000,
-        0x2844004428000000,
-        0x5088008850000000,
-        0xa0100010a0000000,
-        0x4020002040000000,
-        0x0400040200000000,
-        0x0800080500000000,
-        0x1100110a00000000,
-        0x2200221400000000,
-        0x4400442800000000,
-        0x8800885000000000,
-        0x100010a000000000,
-        0x2000204000000000,
-        0x0004020000000000,
-        0x0008050000000000,
-        0x00110a0000000000,
-        0x0022140000000000,
-        0x0044280000000000,
-        0x0088500000000000,
-        0x0010a00000000000,
-        0x0020400000000000,
-    ];
-
-    pub const KING_TABLE: [u64; 64] = [
-        0x0000000000000302,
-        0x0000000000000705,
-        0x0000000000000e0a,
-        0x0000000000001c14,
-        0x0000000000003828,
-        0x0000000000007050,
-        0x000000000000e0a0,
-        0x000000000000c040,
-        0x0000000000030203,
-        0x0000000000070507,
-        0x00000000000e0a0e,
-        0x00000000001c141c,
-        0x0000000000382838,
-        0x0000000000705070,
-        0x0000000000e0a0e0,
-        0x0000000000c040c0,
-        0x0000000003020300,
-        0x0000000007050700,
-        0x000000000e0a0e00,
-        0x000000001c141c00,
-        0x0000000038283800,
-        0x0000000070507000,
-        0x00000000e0a0e000,
-        0x00000000c040c000,
-        0x0000000302030000,
-        0x0000000705070000,
-        0x0000000e0a0e0000,
-        0x0000001c141c0000,
-        0x0000003828380000,
-        0x0000007050700000,
-        0x000000e0a0e00000,
-        0x000000c040c00000,
-        0x0000030203000000,
-        0x0000070507000000,
-        0x00000e0a0e000000,
-        0x00001c141c000000,
-        0x0000382838000000,
-        0x0000705070000000,
-        0x0000e0a0e0000000,
-        0x0000c040c0000000,
-        0x0003020300000000,
-        0x0007050700000000,
-        0x000e0a0e00000000,
-        0x001c141c00000000,
-        0x0038283800000000,
-        0x0070507000000000,
-        0x00e0a0e000000000,
-        0x00c040c000000000,
-        0x0302030000000000,
-        0x0705070000000000,
-        0x0e0a0e0000000000,
-        0x1c141c0000000000,
-        0x3828380000000000,
-        0x7050700000000000,
-        0xe0a0e00000000000,
-        0xc040c00000000000,
-        0x0203000000000000,
-        0x0507000000000000,
-        0x0a0e000000000000,
-        0x141c000000000000,
-        0x2838000000000000,
-        0x5070000000000000,
-        0xa0e0000000000000,
-        0x40c0000000000000,
-    ];
+    pub const KNIGHT_TABLE: [u64; 64] = leaper_table(&[(1, 2), (2, 1), (2, -1), (1, -2), (-1, -2), (-2, -1), (-2, 1), (-1, 2)]);
+    pub const KING_TABLE: [u64; 64] = leaper_table(&[(1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1), (0, -1), (1, -1)]);
 
     #[derive(Debug, Clone, Copy)]
     pub struct Mask {
@@ -142,4626 +11,136 @@ pub mod CONSTS {
         pub rank_mask: u64,
     }
 
-    pub const MASKS: [Mask; 64] = [
-        Mask {
-            diagonal_mask_ex: 0x8040201008040200,
-            antidiag_mask_ex: 0x0000000000000000,
-            file_mask_ex: 0x0101010101010100,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080402010080400,
-            antidiag_mask_ex: 0x0000000000000100,
-            file_mask_ex: 0x0202020202020200,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000804020100800,
-            antidiag_mask_ex: 0x0000000000010200,
-            file_mask_ex: 0x0404040404040400,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000008040201000,
-            antidiag_mask_ex: 0x0000000001020400,
-            file_mask_ex: 0x0808080808080800,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000080402000,
-            antidiag_mask_ex: 0x0000000102040800,
-            file_mask_ex: 0x1010101010101000,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000804000,
-            antidiag_mask_ex: 0x0000010204081000,
-            file_mask_ex: 0x2020202020202000,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000008000,
-            antidiag_mask_ex: 0x0001020408102000,
-            file_mask_ex: 0x4040404040404000,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000000000,
-            antidiag_mask_ex: 0x0102040810204000,
-            file_mask_ex: 0x8080808080808000,
-            rank_mask: 0x00000000000000ff,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4020100804020000,
-            antidiag_mask_ex: 0x0000000000000002,
-            file_mask_ex: 0x0101010101010001,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8040201008040001,
-            antidiag_mask_ex: 0x0000000000010004,
-            file_mask_ex: 0x0202020202020002,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080402010080002,
-            antidiag_mask_ex: 0x0000000001020008,
-            file_mask_ex: 0x0404040404040004,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000804020100004,
-            antidiag_mask_ex: 0x0000000102040010,
-            file_mask_ex: 0x0808080808080008,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000008040200008,
-            antidiag_mask_ex: 0x0000010204080020,
-            file_mask_ex: 0x1010101010100010,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000080400010,
-            antidiag_mask_ex: 0x0001020408100040,
-            file_mask_ex: 0x2020202020200020,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000800020,
-            antidiag_mask_ex: 0x0102040810200080,
-            file_mask_ex: 0x4040404040400040,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000000040,
-            antidiag_mask_ex: 0x0204081020400000,
-            file_mask_ex: 0x8080808080800080,
-            rank_mask: 0x000000000000ff00,
-        },
-        Mask {
-            diagonal_mask_ex: 0x2010080402000000,
-            antidiag_mask_ex: 0x0000000000000204,
-            file_mask_ex: 0x0101010101000101,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4020100804000100,
-            antidiag_mask_ex: 0x0000000001000408,
-            file_mask_ex: 0x0202020202000202,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8040201008000201,
-            antidiag_mask_ex: 0x0000000102000810,
-            file_mask_ex: 0x0404040404000404,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080402010000402,
-            antidiag_mask_ex: 0x0000010204001020,
-            file_mask_ex: 0x0808080808000808,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000804020000804,
-            antidiag_mask_ex: 0x0001020408002040,
-            file_mask_ex: 0x1010101010001010,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000008040001008,
-            antidiag_mask_ex: 0x0102040810004080,
-            file_mask_ex: 0x2020202020002020,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000080002010,
-            antidiag_mask_ex: 0x0204081020008000,
-            file_mask_ex: 0x4040404040004040,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000004020,
-            antidiag_mask_ex: 0x0408102040000000,
-            file_mask_ex: 0x8080808080008080,
-            rank_mask: 0x0000000000ff0000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x1008040200000000,
-            antidiag_mask_ex: 0x0000000000020408,
-            file_mask_ex: 0x0101010100010101,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x2010080400010000,
-            antidiag_mask_ex: 0x0000000100040810,
-            file_mask_ex: 0x0202020200020202,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4020100800020100,
-            antidiag_mask_ex: 0x0000010200081020,
-            file_mask_ex: 0x0404040400040404,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8040201000040201,
-            antidiag_mask_ex: 0x0001020400102040,
-            file_mask_ex: 0x0808080800080808,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080402000080402,
-            antidiag_mask_ex: 0x0102040800204080,
-            file_mask_ex: 0x1010101000101010,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000804000100804,
-            antidiag_mask_ex: 0x0204081000408000,
-            file_mask_ex: 0x2020202000202020,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000008000201008,
-            antidiag_mask_ex: 0x0408102000800000,
-            file_mask_ex: 0x4040404000404040,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000402010,
-            antidiag_mask_ex: 0x0810204000000000,
-            file_mask_ex: 0x8080808000808080,
-            rank_mask: 0x00000000ff000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0804020000000000,
-            antidiag_mask_ex: 0x0000000002040810,
-            file_mask_ex: 0x0101010001010101,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x1008040001000000,
-            antidiag_mask_ex: 0x0000010004081020,
-            file_mask_ex: 0x0202020002020202,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x2010080002010000,
-            antidiag_mask_ex: 0x0001020008102040,
-            file_mask_ex: 0x0404040004040404,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4020100004020100,
-            antidiag_mask_ex: 0x0102040010204080,
-            file_mask_ex: 0x0808080008080808,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8040200008040201,
-            antidiag_mask_ex: 0x0204080020408000,
-            file_mask_ex: 0x1010100010101010,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080400010080402,
-            antidiag_mask_ex: 0x0408100040800000,
-            file_mask_ex: 0x2020200020202020,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000800020100804,
-            antidiag_mask_ex: 0x0810200080000000,
-            file_mask_ex: 0x4040400040404040,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000040201008,
-            antidiag_mask_ex: 0x1020400000000000,
-            file_mask_ex: 0x8080800080808080,
-            rank_mask: 0x000000ff00000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0402000000000000,
-            antidiag_mask_ex: 0x0000000204081020,
-            file_mask_ex: 0x0101000101010101,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0804000100000000,
-            antidiag_mask_ex: 0x0001000408102040,
-            file_mask_ex: 0x0202000202020202,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x1008000201000000,
-            antidiag_mask_ex: 0x0102000810204080,
-            file_mask_ex: 0x0404000404040404,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x2010000402010000,
-            antidiag_mask_ex: 0x0204001020408000,
-            file_mask_ex: 0x0808000808080808,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4020000804020100,
-            antidiag_mask_ex: 0x0408002040800000,
-            file_mask_ex: 0x1010001010101010,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8040001008040201,
-            antidiag_mask_ex: 0x0810004080000000,
-            file_mask_ex: 0x2020002020202020,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0080002010080402,
-            antidiag_mask_ex: 0x1020008000000000,
-            file_mask_ex: 0x4040004040404040,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000004020100804,
-            antidiag_mask_ex: 0x2040000000000000,
-            file_mask_ex: 0x8080008080808080,
-            rank_mask: 0x0000ff0000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0200000000000000,
-            antidiag_mask_ex: 0x0000020408102040,
-            file_mask_ex: 0x0100010101010101,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0400010000000000,
-            antidiag_mask_ex: 0x0100040810204080,
-            file_mask_ex: 0x0200020202020202,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0800020100000000,
-            antidiag_mask_ex: 0x0200081020408000,
-            file_mask_ex: 0x0400040404040404,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x1000040201000000,
-            antidiag_mask_ex: 0x0400102040800000,
-            file_mask_ex: 0x0800080808080808,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x2000080402010000,
-            antidiag_mask_ex: 0x0800204080000000,
-            file_mask_ex: 0x1000101010101010,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x4000100804020100,
-            antidiag_mask_ex: 0x1000408000000000,
-            file_mask_ex: 0x2000202020202020,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x8000201008040201,
-            antidiag_mask_ex: 0x2000800000000000,
-            file_mask_ex: 0x4000404040404040,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000402010080402,
-            antidiag_mask_ex: 0x4000000000000000,
-            file_mask_ex: 0x8000808080808080,
-            rank_mask: 0x00ff000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0000000000000000,
-            antidiag_mask_ex: 0x0002040810204080,
-            file_mask_ex: 0x0001010101010101,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0001000000000000,
-            antidiag_mask_ex: 0x0004081020408000,
-            file_mask_ex: 0x0002020202020202,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0002010000000000,
-            antidiag_mask_ex: 0x0008102040800000,
-            file_mask_ex: 0x0004040404040404,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0004020100000000,
-            antidiag_mask_ex: 0x0010204080000000,
-            file_mask_ex: 0x0008080808080808,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0008040201000000,
-            antidiag_mask_ex: 0x0020408000000000,
-            file_mask_ex: 0x0010101010101010,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0010080402010000,
-            antidiag_mask_ex: 0x0040800000000000,
-            file_mask_ex: 0x0020202020202020,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0020100804020100,
-            antidiag_mask_ex: 0x0080000000000000,
-            file_mask_ex: 0x0040404040404040,
-            rank_mask: 0xff00000000000000,
-        },
-        Mask {
-            diagonal_mask_ex: 0x0040201008040201,
-            antidiag_mask_ex: 0x0000000000000000,
-            file_mask_ex: 0x0080808080808080,
-            rank_mask: 0xff00000000000000,
-        },
-    ];
+    // the _ex masks leave out the square itself, the rank mask keeps it
+    pub const MASKS: [Mask; 64] = masks();
 
     pub const NOT_A_FILE: u64 = 0xFEFEFEFEFEFEFEFE;
     pub const NOT_H_FILE: u64 = 0x7F7F7F7F7F7F7F7F;
+    pub const LIGHT_SQUARES: u64 = 0x55AA55AA55AA55AA;
+    pub const BACK_RANKS: u64 = 0xFF000000000000FF;
+
+    // the squares strictly between two squares on the same line, 0 when they are not aligned
+    pub static SQUARES_BETWEEN: [[u64; 64]; 64] = squares_between();
+    // the whole rank, file or diagonal through two aligned squares, both included, 0 when they are not aligned
+    pub static LINE_THROUGH: [[u64; 64]; 64] = line_through();
+
+    // indexed by [color][castle side], the destination squares are the same in standard chess and Chess960
+    pub const CASTLING_KING_DESTINATION: [[u64; 2]; 2] = [[6, 2], [62, 58]];
+    pub const CASTLING_ROOK_DESTINATION: [[u64; 2]; 2] = [[5, 3], [61, 59]];
+    pub const STANDARD_CASTLING_ROOKS: [[u64; 2]; 2] = [[7, 0], [63, 56]];
+
+    // The tables are computed at compile time, loops are written with while because for isn't allowed in const fn
 
-    pub const SQUARES_BETWEEN: [[u64; 64]; 64] = [
-        [
-            0,
-            0,
-            2,
-            6,
-            14,
-            30,
-            62,
-            126,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            256,
-            0,
-            512,
-            0,
-            0,
-            0,
-            0,
-            0,
-            65792,
-            0,
-            0,
-            262656,
-            0,
-            0,
-            0,
-            0,
-            16843008,
-            0,
-            0,
-            0,
-            134480384,
-            0,
-            0,
-            0,
-            4311810304,
-            0,
-            0,
-            0,
-            0,
-            68853957120,
-            0,
-            0,
-            1103823438080,
-            0,
-            0,
-            0,
-            0,
-            0,
-            35253226045952,
-            0,
-            282578800148736,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18049651735527936,
-        ],
-        [
-            0,
-            0,
-            0,
-            4,
-            12,
-            28,
-            60,
-            124,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            512,
-            0,
-            1024,
-            0,
-            0,
-            0,
-            0,
-            0,
-            131584,
-            0,
-            0,
-            525312,
-            0,
-            0,
-            0,
-            0,
-            33686016,
-            0,
-            0,
-            0,
-            268960768,
-            0,
-            0,
-            0,
-            8623620608,
-            0,
-            0,
-            0,
-            0,
-            137707914240,
-            0,
-            0,
-            2207646876160,
-            0,
-            0,
-            0,
-            0,
-            0,
-            70506452091904,
-            0,
-            565157600297472,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            2,
-            0,
-            0,
-            0,
-            8,
-            24,
-            56,
-            120,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            512,
-            0,
-            1024,
-            0,
-            2048,
-            0,
-            0,
-            0,
-            0,
-            0,
-            263168,
-            0,
-            0,
-            1050624,
-            0,
-            0,
-            0,
-            0,
-            67372032,
-            0,
-            0,
-            0,
-            537921536,
-            0,
-            0,
-            0,
-            17247241216,
-            0,
-            0,
-            0,
-            0,
-            275415828480,
-            0,
-            0,
-            4415293752320,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1130315200594944,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            6,
-            4,
-            0,
-            0,
-            0,
-            16,
-            48,
-            112,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1024,
-            0,
-            2048,
-            0,
-            4096,
-            0,
-            0,
-            132096,
-            0,
-            0,
-            526336,
-            0,
-            0,
-            2101248,
-            0,
-            0,
-            0,
-            0,
-            134744064,
-            0,
-            0,
-            0,
-            1075843072,
-            0,
-            0,
-            0,
-            34494482432,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8830587504640,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2260630401189888,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            14,
-            12,
-            8,
-            0,
-            0,
-            0,
-            32,
-            96,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2048,
-            0,
-            4096,
-            0,
-            8192,
-            0,
-            0,
-            264192,
-            0,
-            0,
-            1052672,
-            0,
-            0,
-            4202496,
-            33818624,
-            0,
-            0,
-            0,
-            269488128,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            68988964864,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17661175009280,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4521260802379776,
-            0,
-            0,
-            0,
-        ],
-        [
-            30,
-            28,
-            24,
-            16,
-            0,
-            0,
-            0,
-            64,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4096,
-            0,
-            8192,
-            0,
-            16384,
-            0,
-            0,
-            528384,
-            0,
-            0,
-            2105344,
-            0,
-            0,
-            0,
-            67637248,
-            0,
-            0,
-            0,
-            538976256,
-            0,
-            0,
-            8657571840,
-            0,
-            0,
-            0,
-            0,
-            137977929728,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            35322350018560,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9042521604759552,
-            0,
-            0,
-        ],
-        [
-            62,
-            60,
-            56,
-            48,
-            32,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8192,
-            0,
-            16384,
-            0,
-            0,
-            0,
-            0,
-            1056768,
-            0,
-            0,
-            4210688,
-            0,
-            0,
-            0,
-            135274496,
-            0,
-            0,
-            0,
-            1077952512,
-            0,
-            0,
-            17315143680,
-            0,
-            0,
-            0,
-            0,
-            275955859456,
-            0,
-            2216338399232,
-            0,
-            0,
-            0,
-            0,
-            0,
-            70644700037120,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18085043209519104,
-            0,
-        ],
-        [
-            126,
-            124,
-            120,
-            112,
-            96,
-            64,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            16384,
-            0,
-            32768,
-            0,
-            0,
-            0,
-            0,
-            2113536,
-            0,
-            0,
-            8421376,
-            0,
-            0,
-            0,
-            270548992,
-            0,
-            0,
-            0,
-            2155905024,
-            0,
-            0,
-            34630287360,
-            0,
-            0,
-            0,
-            0,
-            551911718912,
-            0,
-            4432676798464,
-            0,
-            0,
-            0,
-            0,
-            0,
-            141289400074240,
-            567382630219776,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            36170086419038208,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            512,
-            1536,
-            3584,
-            7680,
-            15872,
-            32256,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            65536,
-            0,
-            131072,
-            0,
-            0,
-            0,
-            0,
-            0,
-            16842752,
-            0,
-            0,
-            67239936,
-            0,
-            0,
-            0,
-            0,
-            4311810048,
-            0,
-            0,
-            0,
-            34426978304,
-            0,
-            0,
-            0,
-            1103823437824,
-            0,
-            0,
-            0,
-            0,
-            17626613022720,
-            0,
-            0,
-            282578800148480,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9024825867763712,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1024,
-            3072,
-            7168,
-            15360,
-            31744,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            131072,
-            0,
-            262144,
-            0,
-            0,
-            0,
-            0,
-            0,
-            33685504,
-            0,
-            0,
-            134479872,
-            0,
-            0,
-            0,
-            0,
-            8623620096,
-            0,
-            0,
-            0,
-            68853956608,
-            0,
-            0,
-            0,
-            2207646875648,
-            0,
-            0,
-            0,
-            0,
-            35253226045440,
-            0,
-            0,
-            565157600296960,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18049651735527424,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            512,
-            0,
-            0,
-            0,
-            2048,
-            6144,
-            14336,
-            30720,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            131072,
-            0,
-            262144,
-            0,
-            524288,
-            0,
-            0,
-            0,
-            0,
-            0,
-            67371008,
-            0,
-            0,
-            268959744,
-            0,
-            0,
-            0,
-            0,
-            17247240192,
-            0,
-            0,
-            0,
-            137707913216,
-            0,
-            0,
-            0,
-            4415293751296,
-            0,
-            0,
-            0,
-            0,
-            70506452090880,
-            0,
-            0,
-            1130315200593920,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1536,
-            1024,
-            0,
-            0,
-            0,
-            4096,
-            12288,
-            28672,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            262144,
-            0,
-            524288,
-            0,
-            1048576,
-            0,
-            0,
-            33816576,
-            0,
-            0,
-            134742016,
-            0,
-            0,
-            537919488,
-            0,
-            0,
-            0,
-            0,
-            34494480384,
-            0,
-            0,
-            0,
-            275415826432,
-            0,
-            0,
-            0,
-            8830587502592,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2260630401187840,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            3584,
-            3072,
-            2048,
-            0,
-            0,
-            0,
-            8192,
-            24576,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            524288,
-            0,
-            1048576,
-            0,
-            2097152,
-            0,
-            0,
-            67633152,
-            0,
-            0,
-            269484032,
-            0,
-            0,
-            1075838976,
-            8657567744,
-            0,
-            0,
-            0,
-            68988960768,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17661175005184,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4521260802375680,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            7680,
-            7168,
-            6144,
-            4096,
-            0,
-            0,
-            0,
-            16384,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1048576,
-            0,
-            2097152,
-            0,
-            4194304,
-            0,
-            0,
-            135266304,
-            0,
-            0,
-            538968064,
-            0,
-            0,
-            0,
-            17315135488,
-            0,
-            0,
-            0,
-            137977921536,
-            0,
-            0,
-            2216338391040,
-            0,
-            0,
-            0,
-            0,
-            35322350010368,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9042521604751360,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            15872,
-            15360,
-            14336,
-            12288,
-            8192,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2097152,
-            0,
-            4194304,
-            0,
-            0,
-            0,
-            0,
-            270532608,
-            0,
-            0,
-            1077936128,
-            0,
-            0,
-            0,
-            34630270976,
-            0,
-            0,
-            0,
-            275955843072,
-            0,
-            0,
-            4432676782080,
-            0,
-            0,
-            0,
-            0,
-            70644700020736,
-            0,
-            567382630203392,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18085043209502720,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            32256,
-            31744,
-            30720,
-            28672,
-            24576,
-            16384,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4194304,
-            0,
-            8388608,
-            0,
-            0,
-            0,
-            0,
-            541065216,
-            0,
-            0,
-            2155872256,
-            0,
-            0,
-            0,
-            69260541952,
-            0,
-            0,
-            0,
-            551911686144,
-            0,
-            0,
-            8865353564160,
-            0,
-            0,
-            0,
-            0,
-            141289400041472,
-            0,
-            1134765260406784,
-            0,
-            0,
-            0,
-            0,
-            0,
-            36170086419005440,
-        ],
-        [
-            256,
-            0,
-            512,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            131072,
-            393216,
-            917504,
-            1966080,
-            4063232,
-            8257536,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            16777216,
-            0,
-            33554432,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4311744512,
-            0,
-            0,
-            17213423616,
-            0,
-            0,
-            0,
-            0,
-            1103823372288,
-            0,
-            0,
-            0,
-            8813306445824,
-            0,
-            0,
-            0,
-            282578800082944,
-            0,
-            0,
-            0,
-            0,
-            4512412933816320,
-            0,
-            0,
-        ],
-        [
-            0,
-            512,
-            0,
-            1024,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            262144,
-            786432,
-            1835008,
-            3932160,
-            8126464,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            33554432,
-            0,
-            67108864,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8623489024,
-            0,
-            0,
-            34426847232,
-            0,
-            0,
-            0,
-            0,
-            2207646744576,
-            0,
-            0,
-            0,
-            17626612891648,
-            0,
-            0,
-            0,
-            565157600165888,
-            0,
-            0,
-            0,
-            0,
-            9024825867632640,
-            0,
-        ],
-        [
-            512,
-            0,
-            1024,
-            0,
-            2048,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            131072,
-            0,
-            0,
-            0,
-            524288,
-            1572864,
-            3670016,
-            7864320,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            33554432,
-            0,
-            67108864,
-            0,
-            134217728,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17246978048,
-            0,
-            0,
-            68853694464,
-            0,
-            0,
-            0,
-            0,
-            4415293489152,
-            0,
-            0,
-            0,
-            35253225783296,
-            0,
-            0,
-            0,
-            1130315200331776,
-            0,
-            0,
-            0,
-            0,
-            18049651735265280,
-        ],
-        [
-            0,
-            1024,
-            0,
-            2048,
-            0,
-            4096,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            393216,
-            262144,
-            0,
-            0,
-            0,
-            1048576,
-            3145728,
-            7340032,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            67108864,
-            0,
-            134217728,
-            0,
-            268435456,
-            0,
-            0,
-            8657043456,
-            0,
-            0,
-            34493956096,
-            0,
-            0,
-            137707388928,
-            0,
-            0,
-            0,
-            0,
-            8830586978304,
-            0,
-            0,
-            0,
-            70506451566592,
-            0,
-            0,
-            0,
-            2260630400663552,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            2048,
-            0,
-            4096,
-            0,
-            8192,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            917504,
-            786432,
-            524288,
-            0,
-            0,
-            0,
-            2097152,
-            6291456,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            134217728,
-            0,
-            268435456,
-            0,
-            536870912,
-            0,
-            0,
-            17314086912,
-            0,
-            0,
-            68987912192,
-            0,
-            0,
-            275414777856,
-            2216337342464,
-            0,
-            0,
-            0,
-            17661173956608,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4521260801327104,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            4096,
-            0,
-            8192,
-            0,
-            16384,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1966080,
-            1835008,
-            1572864,
-            1048576,
-            0,
-            0,
-            0,
-            4194304,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            268435456,
-            0,
-            536870912,
-            0,
-            1073741824,
-            0,
-            0,
-            34628173824,
-            0,
-            0,
-            137975824384,
-            0,
-            0,
-            0,
-            4432674684928,
-            0,
-            0,
-            0,
-            35322347913216,
-            0,
-            0,
-            567382628106240,
-            0,
-            0,
-            0,
-            0,
-            9042521602654208,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            8192,
-            0,
-            16384,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4063232,
-            3932160,
-            3670016,
-            3145728,
-            2097152,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            536870912,
-            0,
-            1073741824,
-            0,
-            0,
-            0,
-            0,
-            69256347648,
-            0,
-            0,
-            275951648768,
-            0,
-            0,
-            0,
-            8865349369856,
-            0,
-            0,
-            0,
-            70644695826432,
-            0,
-            0,
-            1134765256212480,
-            0,
-            0,
-            0,
-            0,
-            18085043205308416,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            16384,
-            0,
-            32768,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8257536,
-            8126464,
-            7864320,
-            7340032,
-            6291456,
-            4194304,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1073741824,
-            0,
-            2147483648,
-            0,
-            0,
-            0,
-            0,
-            138512695296,
-            0,
-            0,
-            551903297536,
-            0,
-            0,
-            0,
-            17730698739712,
-            0,
-            0,
-            0,
-            141289391652864,
-            0,
-            0,
-            2269530512424960,
-            0,
-            0,
-            0,
-            0,
-            36170086410616832,
-        ],
-        [
-            65792,
-            0,
-            0,
-            132096,
-            0,
-            0,
-            0,
-            0,
-            65536,
-            0,
-            131072,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            33554432,
-            100663296,
-            234881024,
-            503316480,
-            1040187392,
-            2113929216,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4294967296,
-            0,
-            8589934592,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1103806595072,
-            0,
-            0,
-            4406636445696,
-            0,
-            0,
-            0,
-            0,
-            282578783305728,
-            0,
-            0,
-            0,
-            2256206450130944,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            131584,
-            0,
-            0,
-            264192,
-            0,
-            0,
-            0,
-            0,
-            131072,
-            0,
-            262144,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            67108864,
-            201326592,
-            469762048,
-            1006632960,
-            2080374784,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8589934592,
-            0,
-            17179869184,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2207613190144,
-            0,
-            0,
-            8813272891392,
-            0,
-            0,
-            0,
-            0,
-            565157566611456,
-            0,
-            0,
-            0,
-            4512412900261888,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            263168,
-            0,
-            0,
-            528384,
-            0,
-            0,
-            131072,
-            0,
-            262144,
-            0,
-            524288,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            33554432,
-            0,
-            0,
-            0,
-            134217728,
-            402653184,
-            939524096,
-            2013265920,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8589934592,
-            0,
-            17179869184,
-            0,
-            34359738368,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4415226380288,
-            0,
-            0,
-            17626545782784,
-            0,
-            0,
-            0,
-            0,
-            1130315133222912,
-            0,
-            0,
-            0,
-            9024825800523776,
-            0,
-        ],
-        [
-            262656,
-            0,
-            0,
-            526336,
-            0,
-            0,
-            1056768,
-            0,
-            0,
-            262144,
-            0,
-            524288,
-            0,
-            1048576,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            100663296,
-            67108864,
-            0,
-            0,
-            0,
-            268435456,
-            805306368,
-            1879048192,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17179869184,
-            0,
-            34359738368,
-            0,
-            68719476736,
-            0,
-            0,
-            2216203124736,
-            0,
-            0,
-            8830452760576,
-            0,
-            0,
-            35253091565568,
-            0,
-            0,
-            0,
-            0,
-            2260630266445824,
-            0,
-            0,
-            0,
-            18049651601047552,
-        ],
-        [
-            0,
-            525312,
-            0,
-            0,
-            1052672,
-            0,
-            0,
-            2113536,
-            0,
-            0,
-            524288,
-            0,
-            1048576,
-            0,
-            2097152,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            234881024,
-            201326592,
-            134217728,
-            0,
-            0,
-            0,
-            536870912,
-            1610612736,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            34359738368,
-            0,
-            68719476736,
-            0,
-            137438953472,
-            0,
-            0,
-            4432406249472,
-            0,
-            0,
-            17660905521152,
-            0,
-            0,
-            70506183131136,
-            567382359670784,
-            0,
-            0,
-            0,
-            4521260532891648,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            1050624,
-            0,
-            0,
-            2105344,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1048576,
-            0,
-            2097152,
-            0,
-            4194304,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            503316480,
-            469762048,
-            402653184,
-            268435456,
-            0,
-            0,
-            0,
-            1073741824,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            68719476736,
-            0,
-            137438953472,
-            0,
-            274877906944,
-            0,
-            0,
-            8864812498944,
-            0,
-            0,
-            35321811042304,
-            0,
-            0,
-            0,
-            1134764719341568,
-            0,
-            0,
-            0,
-            9042521065783296,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            2101248,
-            0,
-            0,
-            4210688,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2097152,
-            0,
-            4194304,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1040187392,
-            1006632960,
-            939524096,
-            805306368,
-            536870912,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            137438953472,
-            0,
-            274877906944,
-            0,
-            0,
-            0,
-            0,
-            17729624997888,
-            0,
-            0,
-            70643622084608,
-            0,
-            0,
-            0,
-            2269529438683136,
-            0,
-            0,
-            0,
-            18085042131566592,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            4202496,
-            0,
-            0,
-            8421376,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4194304,
-            0,
-            8388608,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2113929216,
-            2080374784,
-            2013265920,
-            1879048192,
-            1610612736,
-            1073741824,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            274877906944,
-            0,
-            549755813888,
-            0,
-            0,
-            0,
-            0,
-            35459249995776,
-            0,
-            0,
-            141287244169216,
-            0,
-            0,
-            0,
-            4539058877366272,
-            0,
-            0,
-            0,
-            36170084263133184,
-        ],
-        [
-            16843008,
-            0,
-            0,
-            0,
-            33818624,
-            0,
-            0,
-            0,
-            16842752,
-            0,
-            0,
-            33816576,
-            0,
-            0,
-            0,
-            0,
-            16777216,
-            0,
-            33554432,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8589934592,
-            25769803776,
-            60129542144,
-            128849018880,
-            266287972352,
-            541165879296,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1099511627776,
-            0,
-            2199023255552,
-            0,
-            0,
-            0,
-            0,
-            0,
-            282574488338432,
-            0,
-            0,
-            1128098930098176,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            33686016,
-            0,
-            0,
-            0,
-            67637248,
-            0,
-            0,
-            0,
-            33685504,
-            0,
-            0,
-            67633152,
-            0,
-            0,
-            0,
-            0,
-            33554432,
-            0,
-            67108864,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17179869184,
-            51539607552,
-            120259084288,
-            257698037760,
-            532575944704,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2199023255552,
-            0,
-            4398046511104,
-            0,
-            0,
-            0,
-            0,
-            0,
-            565148976676864,
-            0,
-            0,
-            2256197860196352,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            67372032,
-            0,
-            0,
-            0,
-            135274496,
-            0,
-            0,
-            0,
-            67371008,
-            0,
-            0,
-            135266304,
-            0,
-            0,
-            33554432,
-            0,
-            67108864,
-            0,
-            134217728,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8589934592,
-            0,
-            0,
-            0,
-            34359738368,
-            103079215104,
-            240518168576,
-            515396075520,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2199023255552,
-            0,
-            4398046511104,
-            0,
-            8796093022208,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1130297953353728,
-            0,
-            0,
-            4512395720392704,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            134744064,
-            0,
-            0,
-            0,
-            270548992,
-            67239936,
-            0,
-            0,
-            134742016,
-            0,
-            0,
-            270532608,
-            0,
-            0,
-            67108864,
-            0,
-            134217728,
-            0,
-            268435456,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            25769803776,
-            17179869184,
-            0,
-            0,
-            0,
-            68719476736,
-            206158430208,
-            481036337152,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4398046511104,
-            0,
-            8796093022208,
-            0,
-            17592186044416,
-            0,
-            0,
-            567347999932416,
-            0,
-            0,
-            2260595906707456,
-            0,
-            0,
-            9024791440785408,
-            0,
-        ],
-        [
-            134480384,
-            0,
-            0,
-            0,
-            269488128,
-            0,
-            0,
-            0,
-            0,
-            134479872,
-            0,
-            0,
-            269484032,
-            0,
-            0,
-            541065216,
-            0,
-            0,
-            134217728,
-            0,
-            268435456,
-            0,
-            536870912,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            60129542144,
-            51539607552,
-            34359738368,
-            0,
-            0,
-            0,
-            137438953472,
-            412316860416,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8796093022208,
-            0,
-            17592186044416,
-            0,
-            35184372088832,
-            0,
-            0,
-            1134695999864832,
-            0,
-            0,
-            4521191813414912,
-            0,
-            0,
-            18049582881570816,
-        ],
-        [
-            0,
-            268960768,
-            0,
-            0,
-            0,
-            538976256,
-            0,
-            0,
-            0,
-            0,
-            268959744,
-            0,
-            0,
-            538968064,
-            0,
-            0,
-            0,
-            0,
-            0,
-            268435456,
-            0,
-            536870912,
-            0,
-            1073741824,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            128849018880,
-            120259084288,
-            103079215104,
-            68719476736,
-            0,
-            0,
-            0,
-            274877906944,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17592186044416,
-            0,
-            35184372088832,
-            0,
-            70368744177664,
-            0,
-            0,
-            2269391999729664,
-            0,
-            0,
-            9042383626829824,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            537921536,
-            0,
-            0,
-            0,
-            1077952512,
-            0,
-            0,
-            0,
-            0,
-            537919488,
-            0,
-            0,
-            1077936128,
-            0,
-            0,
-            0,
-            0,
-            0,
-            536870912,
-            0,
-            1073741824,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            266287972352,
-            257698037760,
-            240518168576,
-            206158430208,
-            137438953472,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            35184372088832,
-            0,
-            70368744177664,
-            0,
-            0,
-            0,
-            0,
-            4538783999459328,
-            0,
-            0,
-            18084767253659648,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            1075843072,
-            0,
-            0,
-            0,
-            2155905024,
-            0,
-            0,
-            0,
-            0,
-            1075838976,
-            0,
-            0,
-            2155872256,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1073741824,
-            0,
-            2147483648,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            541165879296,
-            532575944704,
-            515396075520,
-            481036337152,
-            412316860416,
-            274877906944,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            70368744177664,
-            0,
-            140737488355328,
-            0,
-            0,
-            0,
-            0,
-            9077567998918656,
-            0,
-            0,
-            36169534507319296,
-        ],
-        [
-            4311810304,
-            0,
-            0,
-            0,
-            0,
-            8657571840,
-            0,
-            0,
-            4311810048,
-            0,
-            0,
-            0,
-            8657567744,
-            0,
-            0,
-            0,
-            4311744512,
-            0,
-            0,
-            8657043456,
-            0,
-            0,
-            0,
-            0,
-            4294967296,
-            0,
-            8589934592,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2199023255552,
-            6597069766656,
-            15393162788864,
-            32985348833280,
-            68169720922112,
-            138538465099776,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            281474976710656,
-            0,
-            562949953421312,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            8623620608,
-            0,
-            0,
-            0,
-            0,
-            17315143680,
-            0,
-            0,
-            8623620096,
-            0,
-            0,
-            0,
-            17315135488,
-            0,
-            0,
-            0,
-            8623489024,
-            0,
-            0,
-            17314086912,
-            0,
-            0,
-            0,
-            0,
-            8589934592,
-            0,
-            17179869184,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4398046511104,
-            13194139533312,
-            30786325577728,
-            65970697666560,
-            136339441844224,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            562949953421312,
-            0,
-            1125899906842624,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            17247241216,
-            0,
-            0,
-            0,
-            0,
-            34630287360,
-            0,
-            0,
-            17247240192,
-            0,
-            0,
-            0,
-            34630270976,
-            0,
-            0,
-            0,
-            17246978048,
-            0,
-            0,
-            34628173824,
-            0,
-            0,
-            8589934592,
-            0,
-            17179869184,
-            0,
-            34359738368,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2199023255552,
-            0,
-            0,
-            0,
-            8796093022208,
-            26388279066624,
-            61572651155456,
-            131941395333120,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            562949953421312,
-            0,
-            1125899906842624,
-            0,
-            2251799813685248,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            34494482432,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            34494480384,
-            0,
-            0,
-            0,
-            69260541952,
-            17213423616,
-            0,
-            0,
-            34493956096,
-            0,
-            0,
-            69256347648,
-            0,
-            0,
-            17179869184,
-            0,
-            34359738368,
-            0,
-            68719476736,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            6597069766656,
-            4398046511104,
-            0,
-            0,
-            0,
-            17592186044416,
-            52776558133248,
-            123145302310912,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1125899906842624,
-            0,
-            2251799813685248,
-            0,
-            4503599627370496,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            68988964864,
-            0,
-            0,
-            0,
-            34426978304,
-            0,
-            0,
-            0,
-            68988960768,
-            0,
-            0,
-            0,
-            0,
-            34426847232,
-            0,
-            0,
-            68987912192,
-            0,
-            0,
-            138512695296,
-            0,
-            0,
-            34359738368,
-            0,
-            68719476736,
-            0,
-            137438953472,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            15393162788864,
-            13194139533312,
-            8796093022208,
-            0,
-            0,
-            0,
-            35184372088832,
-            105553116266496,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2251799813685248,
-            0,
-            4503599627370496,
-            0,
-            9007199254740992,
-            0,
-        ],
-        [
-            68853957120,
-            0,
-            0,
-            0,
-            0,
-            137977929728,
-            0,
-            0,
-            0,
-            68853956608,
-            0,
-            0,
-            0,
-            137977921536,
-            0,
-            0,
-            0,
-            0,
-            68853694464,
-            0,
-            0,
-            137975824384,
-            0,
-            0,
-            0,
-            0,
-            0,
-            68719476736,
-            0,
-            137438953472,
-            0,
-            274877906944,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            32985348833280,
-            30786325577728,
-            26388279066624,
-            17592186044416,
-            0,
-            0,
-            0,
-            70368744177664,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4503599627370496,
-            0,
-            9007199254740992,
-            0,
-            18014398509481984,
-        ],
-        [
-            0,
-            137707914240,
-            0,
-            0,
-            0,
-            0,
-            275955859456,
-            0,
-            0,
-            0,
-            137707913216,
-            0,
-            0,
-            0,
-            275955843072,
-            0,
-            0,
-            0,
-            0,
-            137707388928,
-            0,
-            0,
-            275951648768,
-            0,
-            0,
-            0,
-            0,
-            0,
-            137438953472,
-            0,
-            274877906944,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            68169720922112,
-            65970697666560,
-            61572651155456,
-            52776558133248,
-            35184372088832,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9007199254740992,
-            0,
-            18014398509481984,
-            0,
-        ],
-        [
-            0,
-            0,
-            275415828480,
-            0,
-            0,
-            0,
-            0,
-            551911718912,
-            0,
-            0,
-            0,
-            275415826432,
-            0,
-            0,
-            0,
-            551911686144,
-            0,
-            0,
-            0,
-            0,
-            275414777856,
-            0,
-            0,
-            551903297536,
-            0,
-            0,
-            0,
-            0,
-            0,
-            274877906944,
-            0,
-            549755813888,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            138538465099776,
-            136339441844224,
-            131941395333120,
-            123145302310912,
-            105553116266496,
-            70368744177664,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18014398509481984,
-            0,
-            36028797018963968,
-        ],
-        [
-            1103823438080,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2216338399232,
-            0,
-            1103823437824,
-            0,
-            0,
-            0,
-            0,
-            2216338391040,
-            0,
-            0,
-            1103823372288,
-            0,
-            0,
-            0,
-            2216337342464,
-            0,
-            0,
-            0,
-            1103806595072,
-            0,
-            0,
-            2216203124736,
-            0,
-            0,
-            0,
-            0,
-            1099511627776,
-            0,
-            2199023255552,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            562949953421312,
-            1688849860263936,
-            3940649673949184,
-            8444249301319680,
-            17451448556060672,
-            35465847065542656,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            2207646876160,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4432676798464,
-            0,
-            2207646875648,
-            0,
-            0,
-            0,
-            0,
-            4432676782080,
-            0,
-            0,
-            2207646744576,
-            0,
-            0,
-            0,
-            4432674684928,
-            0,
-            0,
-            0,
-            2207613190144,
-            0,
-            0,
-            4432406249472,
-            0,
-            0,
-            0,
-            0,
-            2199023255552,
-            0,
-            4398046511104,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1125899906842624,
-            3377699720527872,
-            7881299347898368,
-            16888498602639360,
-            34902897112121344,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            4415293752320,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4415293751296,
-            0,
-            0,
-            0,
-            0,
-            8865353564160,
-            0,
-            0,
-            4415293489152,
-            0,
-            0,
-            0,
-            8865349369856,
-            0,
-            0,
-            0,
-            4415226380288,
-            0,
-            0,
-            8864812498944,
-            0,
-            0,
-            2199023255552,
-            0,
-            4398046511104,
-            0,
-            8796093022208,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            562949953421312,
-            0,
-            0,
-            0,
-            2251799813685248,
-            6755399441055744,
-            15762598695796736,
-            33776997205278720,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            8830587504640,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8830587502592,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8830586978304,
-            0,
-            0,
-            0,
-            17730698739712,
-            4406636445696,
-            0,
-            0,
-            8830452760576,
-            0,
-            0,
-            17729624997888,
-            0,
-            0,
-            4398046511104,
-            0,
-            8796093022208,
-            0,
-            17592186044416,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1688849860263936,
-            1125899906842624,
-            0,
-            0,
-            0,
-            4503599627370496,
-            13510798882111488,
-            31525197391593472,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            17661175009280,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17661175005184,
-            0,
-            0,
-            0,
-            8813306445824,
-            0,
-            0,
-            0,
-            17661173956608,
-            0,
-            0,
-            0,
-            0,
-            8813272891392,
-            0,
-            0,
-            17660905521152,
-            0,
-            0,
-            35459249995776,
-            0,
-            0,
-            8796093022208,
-            0,
-            17592186044416,
-            0,
-            35184372088832,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            3940649673949184,
-            3377699720527872,
-            2251799813685248,
-            0,
-            0,
-            0,
-            9007199254740992,
-            27021597764222976,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            35322350018560,
-            0,
-            0,
-            17626613022720,
-            0,
-            0,
-            0,
-            0,
-            35322350010368,
-            0,
-            0,
-            0,
-            17626612891648,
-            0,
-            0,
-            0,
-            35322347913216,
-            0,
-            0,
-            0,
-            0,
-            17626545782784,
-            0,
-            0,
-            35321811042304,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17592186044416,
-            0,
-            35184372088832,
-            0,
-            70368744177664,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            8444249301319680,
-            7881299347898368,
-            6755399441055744,
-            4503599627370496,
-            0,
-            0,
-            0,
-            18014398509481984,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            35253226045952,
-            0,
-            0,
-            0,
-            0,
-            0,
-            70644700037120,
-            0,
-            0,
-            35253226045440,
-            0,
-            0,
-            0,
-            0,
-            70644700020736,
-            0,
-            0,
-            0,
-            35253225783296,
-            0,
-            0,
-            0,
-            70644695826432,
-            0,
-            0,
-            0,
-            0,
-            35253091565568,
-            0,
-            0,
-            70643622084608,
-            0,
-            0,
-            0,
-            0,
-            0,
-            35184372088832,
-            0,
-            70368744177664,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            17451448556060672,
-            16888498602639360,
-            15762598695796736,
-            13510798882111488,
-            9007199254740992,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            0,
-            70506452091904,
-            0,
-            0,
-            0,
-            0,
-            0,
-            141289400074240,
-            0,
-            0,
-            70506452090880,
-            0,
-            0,
-            0,
-            0,
-            141289400041472,
-            0,
-            0,
-            0,
-            70506451566592,
-            0,
-            0,
-            0,
-            141289391652864,
-            0,
-            0,
-            0,
-            0,
-            70506183131136,
-            0,
-            0,
-            141287244169216,
-            0,
-            0,
-            0,
-            0,
-            0,
-            70368744177664,
-            0,
-            140737488355328,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            35465847065542656,
-            34902897112121344,
-            33776997205278720,
-            31525197391593472,
-            27021597764222976,
-            18014398509481984,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-        ],
-        [
-            282578800148736,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            567382630219776,
-            282578800148480,
-            0,
-            0,
-            0,
-            0,
-            0,
-            567382630203392,
-            0,
-            282578800082944,
-            0,
-            0,
-            0,
-            0,
-            567382628106240,
-            0,
-            0,
-            282578783305728,
-            0,
-            0,
-            0,
-            567382359670784,
-            0,
-            0,
-            0,
-            282574488338432,
-            0,
-            0,
-            567347999932416,
-            0,
-            0,
-            0,
-            0,
-            281474976710656,
-            0,
-            562949953421312,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            144115188075855872,
-            432345564227567616,
-            1008806316530991104,
-            2161727821137838080,
-            4467570830351532032,
-            9079256848778919936,
-        ],
-        [
-            0,
-            565157600297472,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            565157600296960,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1134765260406784,
-            0,
-            565157600165888,
-            0,
-            0,
-            0,
-            0,
-            1134765256212480,
-            0,
-            0,
-            565157566611456,
-            0,
-            0,
-            0,
-            1134764719341568,
-            0,
-            0,
-            0,
-            565148976676864,
-            0,
-            0,
-            1134695999864832,
-            0,
-            0,
-            0,
-            0,
-            562949953421312,
-            0,
-            1125899906842624,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            288230376151711744,
-            864691128455135232,
-            2017612633061982208,
-            4323455642275676160,
-            8935141660703064064,
-        ],
-        [
-            0,
-            0,
-            1130315200594944,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1130315200593920,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1130315200331776,
-            0,
-            0,
-            0,
-            0,
-            2269530512424960,
-            0,
-            0,
-            1130315133222912,
-            0,
-            0,
-            0,
-            2269529438683136,
-            0,
-            0,
-            0,
-            1130297953353728,
-            0,
-            0,
-            2269391999729664,
-            0,
-            0,
-            562949953421312,
-            0,
-            1125899906842624,
-            0,
-            2251799813685248,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            144115188075855872,
-            0,
-            0,
-            0,
-            576460752303423488,
-            1729382256910270464,
-            4035225266123964416,
-            8646911284551352320,
-        ],
-        [
-            0,
-            0,
-            0,
-            2260630401189888,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2260630401187840,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2260630400663552,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2260630266445824,
-            0,
-            0,
-            0,
-            4539058877366272,
-            1128098930098176,
-            0,
-            0,
-            2260595906707456,
-            0,
-            0,
-            4538783999459328,
-            0,
-            0,
-            1125899906842624,
-            0,
-            2251799813685248,
-            0,
-            4503599627370496,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            432345564227567616,
-            288230376151711744,
-            0,
-            0,
-            0,
-            1152921504606846976,
-            3458764513820540928,
-            8070450532247928832,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            4521260802379776,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4521260802375680,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4521260801327104,
-            0,
-            0,
-            0,
-            2256206450130944,
-            0,
-            0,
-            0,
-            4521260532891648,
-            0,
-            0,
-            0,
-            0,
-            2256197860196352,
-            0,
-            0,
-            4521191813414912,
-            0,
-            0,
-            9077567998918656,
-            0,
-            0,
-            2251799813685248,
-            0,
-            4503599627370496,
-            0,
-            9007199254740992,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            1008806316530991104,
-            864691128455135232,
-            576460752303423488,
-            0,
-            0,
-            0,
-            2305843009213693952,
-            6917529027641081856,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            9042521604759552,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9042521604751360,
-            0,
-            0,
-            4512412933816320,
-            0,
-            0,
-            0,
-            0,
-            9042521602654208,
-            0,
-            0,
-            0,
-            4512412900261888,
-            0,
-            0,
-            0,
-            9042521065783296,
-            0,
-            0,
-            0,
-            0,
-            4512395720392704,
-            0,
-            0,
-            9042383626829824,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4503599627370496,
-            0,
-            9007199254740992,
-            0,
-            18014398509481984,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            2161727821137838080,
-            2017612633061982208,
-            1729382256910270464,
-            1152921504606846976,
-            0,
-            0,
-            0,
-            4611686018427387904,
-        ],
-        [
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18085043209519104,
-            0,
-            9024825867763712,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18085043209502720,
-            0,
-            0,
-            9024825867632640,
-            0,
-            0,
-            0,
-            0,
-            18085043205308416,
-            0,
-            0,
-            0,
-            9024825800523776,
-            0,
-            0,
-            0,
-            18085042131566592,
-            0,
-            0,
-            0,
-            0,
-            9024791440785408,
-            0,
-            0,
-            18084767253659648,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9007199254740992,
-            0,
-            18014398509481984,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            4467570830351532032,
-            4323455642275676160,
-            4035225266123964416,
-            3458764513820540928,
-            2305843009213693952,
-            0,
-            0,
-            0,
-        ],
-        [
-            18049651735527936,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            36170086419038208,
-            0,
-            18049651735527424,
-            0,
-            0,
-            0,
-            0,
-            0,
-            36170086419005440,
-            0,
-            0,
-            18049651735265280,
-            0,
-            0,
-            0,
-            0,
-            36170086410616832,
-            0,
-            0,
-            0,
-            18049651601047552,
-            0,
-            0,
-            0,
-            36170084263133184,
-            0,
-            0,
-            0,
-            0,
-            18049582881570816,
-            0,
-            0,
-            36169534507319296,
-            0,
-            0,
-            0,
-            0,
-            0,
-            18014398509481984,
-            0,
-            36028797018963968,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            0,
-            9079256848778919936,
-            8935141660703064064,
-            8646911284551352320,
-            8070450532247928832,
-            6917529027641081856,
-            4611686018427387904,
-            0,
-            0,
-        ],
-    ];
+    /// The squares at (file, rank) offsets from each square that are still on the board
+    const fn leaper_table(offsets: &[(i64, i64)]) -> [u64; 64] {
+        let mut res = [0u64; 64];
+        let mut square = 0;
+        while square < 64 {
+            let mut i = 0;
+            while i < offsets.len() {
+                let file = (square % 8) as i64 + offsets[i].0;
+                let rank = (square / 8) as i64 + offsets[i].1;
+                if file >= 0 && file < 8 && rank >= 0 && rank < 8 {
+                    res[square] |= 1 << (rank * 8 + file);
+                }
+                i += 1;
+            }
+            square += 1;
+        }
+        res
+    }
+
+    /// Every square reached by stepping from square in one direction and in the opposite one, square excluded
+    const fn line_ex(square: usize, file_step: i64, rank_step: i64) -> u64 {
+        let mut res = 0u64;
+        let mut sign = -1;
+        while sign <= 1 {
+            let mut file = (square % 8) as i64 + sign * file_step;
+            let mut rank = (square / 8) as i64 + sign * rank_step;
+            while file >= 0 && file < 8 && rank >= 0 && rank < 8 {
+                res |= 1 << (rank * 8 + file);
+                file += sign * file_step;
+                rank += sign * rank_step;
+            }
+            sign += 2;
+        }
+        res
+    }
 
-    pub const CASTLING_MASKS_KINGSIDE: [u64; 2] = [0x60, 0x6000000000000000];
-    pub const CASTLING_EMPTY_MASKS_QUEENSIDE: [u64; 2] = [0xE, 0xE00000000000000];
-    pub const CASTLING_ATTACKED_MASKS_QUEENSIDE: [u64; 2] = [0xC, 0xC00000000000000];
-    pub const CASTLING_ROOK_INDEX_KINGSIDE: [u64; 2] = [0x80, 0x8000000000000000];
-    pub const CASTLING_ROOK_INDEX_QUEENSIDE: [u64; 2]= [0x1, 0x100000000000000];
+    const fn masks() -> [Mask; 64] {
+        let mut res = [Mask {
+            diagonal_mask_ex: 0,
+            antidiag_mask_ex: 0,
+            file_mask_ex: 0,
+            rank_mask: 0,
+        }; 64];
+        let mut square = 0;
+        while square < 64 {
+            res[square] = Mask {
+                diagonal_mask_ex: line_ex(square, 1, 1),
+                antidiag_mask_ex: line_ex(square, 1, -1),
+                file_mask_ex: line_ex(square, 0, 1),
+                rank_mask: line_ex(square, 1, 0) | 1 << square,
+            };
+            square += 1;
+        }
+        res
+    }
+
+    /// The line through both squares with both squares included, 0 when they are equal or not aligned
+    const fn shared_line(a: usize, b: usize) -> u64 {
+        let mask = &MASKS[a];
+        let b_bit = 1u64 << b;
+        let line_ex = if a == b {
+            0
+        } else if mask.diagonal_mask_ex & b_bit != 0 {
+            mask.diagonal_mask_ex
+        } else if mask.antidiag_mask_ex & b_bit != 0 {
+            mask.antidiag_mask_ex
+        } else if mask.file_mask_ex & b_bit != 0 {
+            mask.file_mask_ex
+        } else if mask.rank_mask & b_bit != 0 {
+            mask.rank_mask & !(1 << a)
+        } else {
+            0
+        };
+
+        if line_ex == 0 {
+            0
+        } else {
+            line_ex | 1 << a
+        }
+    }
+
+    const fn squares_between() -> [[u64; 64]; 64] {
+        let mut res = [[0u64; 64]; 64];
+        let mut a = 0;
+        while a < 64 {
+            let mut b = 0;
+            while b < 64 {
+                // the squares of the line above the lower square and below the higher one
+                let (low, high) = if a < b { (a, b) } else { (b, a) };
+                let above_low = (u64::MAX << low) & !(1u64 << low);
+                let below_high = (1u64 << high) - 1;
+                res[a][b] = shared_line(a, b) & above_low & below_high;
+                b += 1;
+            }
+            a += 1;
+        }
+        res
+    }
+
+    const fn line_through() -> [[u64; 64]; 64] {
+        let mut res = [[0u64; 64]; 64];
+        let mut a = 0;
+        while a < 64 {
+            let mut b = 0;
+            while b < 64 {
+                res[a][b] = shared_line(a, b);
+                b += 1;
+            }
+            a += 1;
+        }
+        res
+    }
 }
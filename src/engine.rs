@@ -1,18 +1,34 @@
 use std::time::Instant;
 
-use crate::{board::Board, chess_move::Move, move_generation::generate_legal_moves, move_list::MoveList};
+use crate::piece::PieceType;
+use crate::move_generation::{generate_legal_moves, generate_moves, GenType};
+use crate::{board::Board, chess_move::Move, move_list::MoveList};
 
-const PIECE_WEIGHTS: [i32; 6] = [500, 330, 900, 300, 100, 0];
-const COLOR_MULTIPLIERS: [i32; 2] = [1, -1];
+const MATE_SCORE: i32 = 1_000_000;
+const NULL_MOVE_REDUCTION: u8 = 2;
 
 pub fn play_next_move(board: &mut Board) {
+    let now = Instant::now();
+
+    // checkmate or stalemate, there is nothing to play
+    let Some((best_move, max_eval)) = search(board) else {
+        return;
+    };
+
+    println!("Current eval: {} | {}s", max_eval, now.elapsed().as_secs_f32());
+    board.make_move(best_move);
+    println!("Position: {}", board.to_fen());
+}
+
+/// Returns the best move found at ENGINE_DEPTH with its eval from the side to move, None when there are no legal moves
+pub fn search(board: &mut Board) -> Option<(Move, i32)> {
     let mut best_move: Option<Move> = None;
     let mut max_eval = i32::MIN;
 
     let mut alpha = i32::MIN + 1;
     let beta = i32::MAX - 1;
 
-    let now = Instant::now();
+    debug_assert_symmetric(board);
 
     let mut legal_moves = generate_legal_moves(board);
     order_moves(&mut legal_moves, board);
@@ -28,33 +44,108 @@ pub fn play_next_move(board: &mut Board) {
         }
     }
 
-    println!("Current eval: {} | {}s", max_eval, now.elapsed().as_secs_f32());
-    board.make_move(best_move.unwrap());
+    best_move.map(|m| (m, max_eval))
 }
 
 fn alpha_beta(board: &mut Board, mut alpha: i32, beta: i32, depth: u8) -> i32 {
+    if board.has_insufficient_material() {
+        return 0;
+    }
+
     if depth == 0 {
-        return eval(board);
+        return quiescence(board, alpha, beta);
+    }
+
+    let in_check = board.is_in_check();
+    // a repeated position had legal moves the first time around, but a mate given on the last move before the
+    // fifty move limit still counts
+    if board.is_repetition()
+        || (board.is_fifty_move_draw() && !(in_check && generate_moves(board, GenType::Evasions).len() == 0))
+    {
+        return 0;
+    }
+
+    // null move pruning: if passing the turn still fails high the position is good enough to cut. Skipped in check,
+    // where passing is illegal, and with only pawns left, where zugzwang makes the assumption unsound
+    if depth >= 3 && !in_check && has_non_pawn_material(board) {
+        board.make_null_move();
+        let null_move_eval = -alpha_beta(board, -beta, -beta + 1, depth - 1 - NULL_MOVE_REDUCTION);
+        board.unmake_null_move();
+
+        if null_move_eval >= beta {
+            return beta;
+        }
     }
 
     let mut max_eval = i32::MIN + 1;
+    let mut move_count = 0;
+
+    // the quiet moves are only generated when no capture caused a cutoff
+    let stages: &[GenType] = if in_check {
+        &[GenType::Evasions]
+    } else {
+        &[GenType::Captures, GenType::Quiets]
+    };
+    for &stage in stages {
+        let mut moves = generate_moves(board, stage);
+        order_moves(&mut moves, board);
+        move_count += moves.len();
+
+        for m in moves.iter() {
+            board.make_move(m);
+            let this_move_eval = -alpha_beta(board, -beta, -alpha, depth - 1);
+            board.unmake_move(m);
+
+            if this_move_eval > max_eval {
+                max_eval = this_move_eval;
+                if this_move_eval > alpha {
+                    alpha = this_move_eval;
+                }
+            }
 
-    let mut legal_moves = generate_legal_moves(board);
-    if legal_moves.len() == 0 {
-        return max_eval;
+            if this_move_eval >= beta {
+                return max_eval;
+            }
+        }
     }
-    order_moves(&mut legal_moves, board);
 
-    for m in legal_moves.iter() {
+    if move_count == 0 {
+        // mates closer to the root are worse for the mated side, so the engine goes for the fastest mate
+        return if in_check { -MATE_SCORE - depth as i32 } else { 0 };
+    }
+    max_eval
+}
+
+/// Searches captures only until the position is quiet, so that the eval is never taken in the middle of an exchange.
+/// The side to move can always stand pat instead of capturing, and captures losing material are skipped
+fn quiescence(board: &mut Board, mut alpha: i32, beta: i32) -> i32 {
+    if board.has_insufficient_material() {
+        return 0;
+    }
+
+    let stand_pat = eval(board);
+    if stand_pat >= beta {
+        return stand_pat;
+    }
+    alpha = alpha.max(stand_pat);
+
+    let mut captures = MoveList::new();
+    for m in generate_moves(board, GenType::Captures).iter() {
+        if board.see_ge(m, 0) {
+            captures.push(m);
+        }
+    }
+    order_moves(&mut captures, board);
+
+    let mut max_eval = stand_pat;
+    for m in captures.iter() {
         board.make_move(m);
-        let this_move_eval = -alpha_beta(board, -beta, -alpha, depth - 1);
+        let this_move_eval = -quiescence(board, -beta, -alpha);
         board.unmake_move(m);
 
         if this_move_eval > max_eval {
             max_eval = this_move_eval;
-            if this_move_eval > alpha {
-                alpha = this_move_eval;
-            }
+            alpha = alpha.max(this_move_eval);
         }
 
         if this_move_eval >= beta {
@@ -64,35 +155,47 @@ fn alpha_beta(board: &mut Board, mut alpha: i32, beta: i32, depth: u8) -> i32 {
     max_eval
 }
 
-fn eval(board: &Board) -> i32 {
-    let mut res = 0;
-
-    for (piece_color, bbs_ar) in board.get_pieces_bb().iter().enumerate() {
-        for (piece_type, bb) in bbs_ar.iter().enumerate() {
-            res += PIECE_WEIGHTS[piece_type] * COLOR_MULTIPLIERS[piece_color] * bb.count_ones() as i32;
-        }
+/// The evaluation must not depend on which color is which, and without castling rights the move generation must not
+/// depend on which side of the board the pieces are on
+fn debug_assert_symmetric(board: &Board) {
+    debug_assert_eq!(eval(board), eval(&board.mirrored()), "eval of the color flipped position differs");
+
+    if board.current_gamestate.get_castling_rights() == 0 {
+        debug_assert_eq!(
+            board.get_legal_moves().len(),
+            board.flipped_horizontally().get_legal_moves().len(),
+            "legal move count of the horizontally flipped position differs"
+        );
     }
+}
 
-    res * COLOR_MULTIPLIERS[board.get_color_to_move()]
+fn has_non_pawn_material(board: &Board) -> bool {
+    let pieces_bb = board.get_pieces_bb()[board.get_color_to_move()];
+    [PieceType::Rook, PieceType::Bishop, PieceType::Queen, PieceType::Knight]
+        .into_iter()
+        .any(|piece_type| pieces_bb[piece_type as usize] != 0)
+}
+
+fn eval(board: &Board) -> i32 {
+    board.get_tapered_score()
 }
 
 fn order_moves(moves: &mut MoveList, board: &Board) {
     let mut scores: Vec<i32> = vec![0; moves.len() as usize];
 
     for (i, m) in moves.iter().enumerate() {
-        if let Some(captured_piece) = board.get_piece_at(m.get_to()) {
-            scores[i] = PIECE_WEIGHTS[captured_piece.get_type()] - PIECE_WEIGHTS[m.get_moved_piece().get_type()];
-        }
-
-        if m.is_promotion() {
-            scores[i] += PIECE_WEIGHTS[m.get_promotion_type()];
+        // captures and promotions are ordered by what they win after the exchange, losing ones end up after the
+        // quiet moves
+        let is_capture = m.get_castle_side().is_none() && board.get_piece_at(m.get_to()).is_some();
+        if is_capture || m.is_enpassant() || m.is_promotion() {
+            scores[i] = board.see(m);
         }
     }
 
     quick_sort(moves, &mut scores, 0, moves.len() as isize - 1);
 }
 
-fn quick_sort(moves: &mut MoveList, scores: &mut Vec<i32>, low: isize, high: isize) {
+fn quick_sort(moves: &mut MoveList, scores: &mut [i32], low: isize, high: isize) {
     if low < high {
         let pivot_index = partition(moves, scores, low, high);
         quick_sort(moves, scores, low, pivot_index - 1);
@@ -100,7 +203,7 @@ fn quick_sort(moves: &mut MoveList, scores: &mut Vec<i32>, low: isize, high: isi
     }
 }
 
-fn partition(moves: &mut MoveList, scores: &mut Vec<i32>, low: isize, high: isize) -> isize {
+fn partition(moves: &mut MoveList, scores: &mut [i32], low: isize, high: isize) -> isize {
     let pivot_score = scores[high as usize];
     let mut i = low - 1;
 
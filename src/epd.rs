@@ -0,0 +1,331 @@
+use std::fmt;
+
+use crate::{
+    board::Board,
+    chess_move::Move,
+    fen::FenError,
+    san::{self, SanError},
+};
+
+/// Operands are typed by their opcode: move lists for bm, am, pm and sm, text for id and the comments c0 to c9
+/// and integers for the counters and analysis results. Unknown opcodes keep their operands verbatim
+#[derive(Clone, PartialEq, Debug)]
+pub enum EpdOperand {
+    Moves(Vec<Move>),
+    Text(String),
+    Integer(i64),
+    Tokens(Vec<EpdToken>),
+}
+
+/// An operand of an unknown opcode, strings stay strings so that they are quoted again when written
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EpdToken {
+    Word(String),
+    Text(String),
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum EpdError {
+    Fen(FenError),
+    InvalidOpcode { offset: usize },
+    UnterminatedString { offset: usize },
+    InvalidNumber { offset: usize },
+    InvalidMove { offset: usize, error: SanError },
+}
+
+impl fmt::Display for EpdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EpdError::Fen(e) => write!(f, "{e}"),
+            EpdError::InvalidOpcode { offset } => write!(f, "invalid opcode at offset {offset}"),
+            EpdError::UnterminatedString { offset } => write!(f, "unterminated string at offset {offset}"),
+            EpdError::InvalidNumber { offset } => write!(f, "invalid number at offset {offset}"),
+            EpdError::InvalidMove { offset, error } => write!(f, "invalid move at offset {offset}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for EpdError {}
+
+impl From<FenError> for EpdError {
+    fn from(e: FenError) -> Self {
+        EpdError::Fen(e)
+    }
+}
+
+/// A position with its operations, in the order they appear in the record
+pub struct Epd {
+    pub board: Board,
+    pub operations: Vec<(String, EpdOperand)>,
+}
+
+/// An operation as it appears in the record, before its operands are typed
+struct RawOperation<'a> {
+    opcode: &'a str,
+    offset: usize,
+    operands: Vec<RawOperand<'a>>,
+}
+
+/// An operand as it appears in the record, strings are stored without their quotes
+struct RawOperand<'a> {
+    offset: usize,
+    text: &'a str,
+    quoted: bool,
+}
+
+const MOVE_OPCODES: [&str; 4] = ["bm", "am", "pm", "sm"];
+const TEXT_OPCODES: [&str; 11] = ["id", "c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9"];
+const INTEGER_OPCODES: [&str; 8] = ["acd", "acn", "acs", "ce", "dm", "fmvn", "hmvc", "rc"];
+
+impl Epd {
+    /// Parses one EPD record: the first four FEN fields followed by operations terminated by semicolons.
+    /// The hmvc and fmvn operations set the move counters of the board
+    pub fn parse(record: &str) -> Result<Epd, EpdError> {
+        let fen_end = fen_fields_end(record);
+        let operations = split_operations(record, fen_end)?;
+
+        let mut counters = [0, 1];
+        for (opcode_index, opcode) in ["hmvc", "fmvn"].into_iter().enumerate() {
+            if let Some(operation) = operations.iter().find(|operation| operation.opcode == opcode) {
+                let value = parse_integer(operation.offset, &operation.operands)?;
+                counters[opcode_index] = u16::try_from(value).map_err(|_| EpdError::InvalidNumber {
+                    offset: operation.offset,
+                })?;
+            }
+        }
+        // with fields missing the counters are left out so that the FEN parser reports the missing field
+        let fen = &record[..fen_end];
+        let board = match fen.split_whitespace().count() {
+            4 => Board::from_fen(&format!("{fen} {} {}", counters[0], counters[1]))?,
+            _ => Board::from_fen(fen)?,
+        };
+
+        let mut typed_operations = Vec::with_capacity(operations.len());
+        for RawOperation {
+            opcode,
+            offset,
+            operands,
+        } in operations
+        {
+            let operand = if MOVE_OPCODES.contains(&opcode) {
+                let moves = operands
+                    .iter()
+                    .map(|operand| {
+                        san::parse_san(&board, operand.text).map_err(|error| EpdError::InvalidMove {
+                            offset: operand.offset,
+                            error,
+                        })
+                    })
+                    .collect::<Result<Vec<Move>, EpdError>>()?;
+                EpdOperand::Moves(moves)
+            } else if TEXT_OPCODES.contains(&opcode) {
+                EpdOperand::Text(operands.iter().map(|operand| operand.text).collect::<Vec<&str>>().join(" "))
+            } else if INTEGER_OPCODES.contains(&opcode) {
+                EpdOperand::Integer(parse_integer(offset, &operands)?)
+            } else {
+                EpdOperand::Tokens(
+                    operands
+                        .iter()
+                        .map(|operand| {
+                            if operand.quoted {
+                                EpdToken::Text(operand.text.to_string())
+                            } else {
+                                EpdToken::Word(operand.text.to_string())
+                            }
+                        })
+                        .collect(),
+                )
+            };
+
+            typed_operations.push((opcode.to_string(), operand));
+        }
+
+        Ok(Epd {
+            board,
+            operations: typed_operations,
+        })
+    }
+
+    /// Parses every record of an EPD file, blank lines are skipped
+    pub fn parse_all(contents: &str) -> Vec<Result<Epd, EpdError>> {
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(Epd::parse)
+            .collect()
+    }
+
+    pub fn get(&self, opcode: &str) -> Option<&EpdOperand> {
+        self.operations
+            .iter()
+            .find(|(name, _)| name == opcode)
+            .map(|(_, operand)| operand)
+    }
+
+    /// The moves of the bm operation, empty when there is none
+    pub fn best_moves(&self) -> &[Move] {
+        match self.get("bm") {
+            Some(EpdOperand::Moves(moves)) => moves,
+            _ => &[],
+        }
+    }
+
+    /// The moves of the am operation, empty when there is none
+    pub fn avoid_moves(&self) -> &[Move] {
+        match self.get("am") {
+            Some(EpdOperand::Moves(moves)) => moves,
+            _ => &[],
+        }
+    }
+
+    pub fn id(&self) -> Option<&str> {
+        match self.get("id") {
+            Some(EpdOperand::Text(id)) => Some(id),
+            _ => None,
+        }
+    }
+
+    /// Writes the record back with moves in SAN, the board is borrowed mutably to find out which moves give check
+    pub fn write(&mut self) -> String {
+        let fen = self.board.to_fen();
+        let mut res = fen.split(' ').take(4).collect::<Vec<&str>>().join(" ");
+
+        for (opcode, operand) in &self.operations {
+            res.push(' ');
+            res.push_str(opcode);
+
+            match operand {
+                EpdOperand::Moves(moves) => {
+                    for &m in moves {
+                        res.push(' ');
+                        res.push_str(&san::move_to_san(&mut self.board, m));
+                    }
+                }
+                EpdOperand::Text(text) => res.push_str(&format!(" \"{text}\"")),
+                EpdOperand::Integer(value) => res.push_str(&format!(" {value}")),
+                EpdOperand::Tokens(tokens) => {
+                    for token in tokens {
+                        match token {
+                            EpdToken::Word(word) => res.push_str(&format!(" {word}")),
+                            EpdToken::Text(text) => res.push_str(&format!(" \"{text}\"")),
+                        }
+                    }
+                }
+            }
+            res.push(';');
+        }
+
+        res
+    }
+}
+
+/// Returns the byte offset right after the fourth whitespace separated field
+fn fen_fields_end(record: &str) -> usize {
+    let mut fields_seen = 0;
+    let mut in_field = false;
+
+    for (offset, c) in record.char_indices() {
+        if c.is_ascii_whitespace() {
+            if in_field {
+                fields_seen += 1;
+                if fields_seen == 4 {
+                    return offset;
+                }
+            }
+            in_field = false;
+        } else {
+            in_field = true;
+        }
+    }
+
+    record.len()
+}
+
+/// Splits the operations following the FEN fields into opcode, opcode offset and operands.
+/// The semicolon after the last operation is optional
+fn split_operations(record: &str, start: usize) -> Result<Vec<RawOperation<'_>>, EpdError> {
+    let bytes = record.as_bytes();
+    let mut operations = Vec::new();
+    let mut offset = start;
+
+    let skip_whitespace = |mut offset: usize| {
+        while offset < bytes.len() && bytes[offset].is_ascii_whitespace() {
+            offset += 1;
+        }
+        offset
+    };
+    let token_end = |mut offset: usize| {
+        while offset < bytes.len() && !bytes[offset].is_ascii_whitespace() && bytes[offset] != b';' {
+            offset += 1;
+        }
+        offset
+    };
+
+    loop {
+        offset = skip_whitespace(offset);
+        if offset == bytes.len() {
+            break;
+        }
+
+        let opcode_start = offset;
+        offset = token_end(offset);
+        let opcode = &record[opcode_start..offset];
+        let valid_opcode = opcode.len() <= 15
+            && opcode.starts_with(|c: char| c.is_ascii_alphabetic())
+            && opcode.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_opcode {
+            return Err(EpdError::InvalidOpcode { offset: opcode_start });
+        }
+
+        let mut operands = Vec::new();
+        loop {
+            offset = skip_whitespace(offset);
+            if offset == bytes.len() {
+                break;
+            }
+            if bytes[offset] == b';' {
+                offset += 1;
+                break;
+            }
+
+            if bytes[offset] == b'"' {
+                let string_end = record[offset + 1..]
+                    .find('"')
+                    .ok_or(EpdError::UnterminatedString { offset })?;
+                operands.push(RawOperand {
+                    offset: offset + 1,
+                    text: &record[offset + 1..offset + 1 + string_end],
+                    quoted: true,
+                });
+                offset += string_end + 2;
+            } else {
+                let operand_start = offset;
+                offset = token_end(offset);
+                operands.push(RawOperand {
+                    offset: operand_start,
+                    text: &record[operand_start..offset],
+                    quoted: false,
+                });
+            }
+        }
+
+        operations.push(RawOperation {
+            opcode,
+            offset: opcode_start,
+            operands,
+        });
+    }
+
+    Ok(operations)
+}
+
+/// Integer operations take exactly one unquoted operand
+fn parse_integer(opcode_offset: usize, operands: &[RawOperand]) -> Result<i64, EpdError> {
+    match operands {
+        [operand] if !operand.quoted => operand
+            .text
+            .parse()
+            .map_err(|_| EpdError::InvalidNumber { offset: operand.offset }),
+        _ => Err(EpdError::InvalidNumber { offset: opcode_offset }),
+    }
+}
@@ -0,0 +1,418 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    bitboard::Bitmanip, consts::CONSTS, gamestate::CastleSide, piece::*, square_to_str, str_to_square,
+    validation::ValidationError,
+};
+
+/// The six space separated fields of a FEN string, in the order they appear
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FenField {
+    PiecePlacement,
+    ColorToMove,
+    CastlingRights,
+    EnpassantSquare,
+    HalfmoveClock,
+    FullmoveNumber,
+}
+
+impl FenField {
+    pub const ALL: [FenField; 6] = [
+        FenField::PiecePlacement,
+        FenField::ColorToMove,
+        FenField::CastlingRights,
+        FenField::EnpassantSquare,
+        FenField::HalfmoveClock,
+        FenField::FullmoveNumber,
+    ];
+}
+
+impl fmt::Display for FenField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FenField::PiecePlacement => "piece placement",
+            FenField::ColorToMove => "color to move",
+            FenField::CastlingRights => "castling rights",
+            FenField::EnpassantSquare => "en passant square",
+            FenField::HalfmoveClock => "halfmove clock",
+            FenField::FullmoveNumber => "fullmove number",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Every variant carries the field that failed and the byte offset in the FEN string where the problem was found.
+/// IllegalPosition is reported for well formed strings describing a position that can't occur in a game
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FenError {
+    MissingField { field: FenField, offset: usize },
+    TooManyFields { offset: usize },
+    UnexpectedChar { field: FenField, offset: usize, found: char },
+    BadRankLength { offset: usize },
+    BadRankCount { offset: usize },
+    InvalidSquare { offset: usize },
+    InvalidNumber { field: FenField, offset: usize },
+    IllegalPosition { offset: usize, error: ValidationError },
+}
+
+impl FenError {
+    pub fn field(&self) -> Option<FenField> {
+        match *self {
+            FenError::MissingField { field, .. } => Some(field),
+            FenError::TooManyFields { .. } => None,
+            FenError::UnexpectedChar { field, .. } => Some(field),
+            FenError::BadRankLength { .. } | FenError::BadRankCount { .. } => Some(FenField::PiecePlacement),
+            FenError::InvalidSquare { .. } => Some(FenField::EnpassantSquare),
+            FenError::InvalidNumber { field, .. } => Some(field),
+            FenError::IllegalPosition { error, .. } => Some(error.fen_field()),
+        }
+    }
+
+    pub fn offset(&self) -> usize {
+        match *self {
+            FenError::MissingField { offset, .. }
+            | FenError::TooManyFields { offset }
+            | FenError::UnexpectedChar { offset, .. }
+            | FenError::BadRankLength { offset }
+            | FenError::BadRankCount { offset }
+            | FenError::InvalidSquare { offset }
+            | FenError::InvalidNumber { offset, .. }
+            | FenError::IllegalPosition { offset, .. } => offset,
+        }
+    }
+}
+
+impl fmt::Display for FenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FenError::MissingField { field, offset } => write!(f, "missing {field} field at offset {offset}"),
+            FenError::TooManyFields { offset } => write!(f, "unexpected extra field at offset {offset}"),
+            FenError::UnexpectedChar { field, offset, found } => {
+                write!(f, "unexpected character '{found}' in {field} field at offset {offset}")
+            }
+            FenError::BadRankLength { offset } => write!(f, "rank does not cover exactly 8 files at offset {offset}"),
+            FenError::BadRankCount { offset } => write!(f, "piece placement does not have exactly 8 ranks at offset {offset}"),
+            FenError::InvalidSquare { offset } => write!(f, "invalid en passant square at offset {offset}"),
+            FenError::InvalidNumber { field, offset } => write!(f, "invalid number in {field} field at offset {offset}"),
+            FenError::IllegalPosition { offset, error } => write!(f, "illegal position, {error} (offset {offset})"),
+        }
+    }
+}
+
+impl std::error::Error for FenError {}
+
+/// Splits a FEN string into its fields, each paired with the byte offset where it starts.
+/// The halfmove clock and fullmove number are optional, so the result has 4 to 6 entries
+pub fn split_fields(fen_string: &str) -> Result<Vec<(usize, &str)>, FenError> {
+    let mut fields = Vec::with_capacity(6);
+    let mut field_start: Option<usize> = None;
+
+    for (offset, c) in fen_string.char_indices() {
+        match (c.is_ascii_whitespace(), field_start) {
+            (true, Some(start)) => {
+                fields.push((start, &fen_string[start..offset]));
+                field_start = None;
+            }
+            (false, None) => field_start = Some(offset),
+            _ => {}
+        }
+    }
+    if let Some(start) = field_start {
+        fields.push((start, &fen_string[start..]));
+    }
+
+    if fields.len() > FenField::ALL.len() {
+        return Err(FenError::TooManyFields {
+            offset: fields[FenField::ALL.len()].0,
+        });
+    }
+    if fields.len() < 4 {
+        return Err(FenError::MissingField {
+            field: FenField::ALL[fields.len()],
+            offset: fen_string.len(),
+        });
+    }
+
+    Ok(fields)
+}
+
+pub fn parse_piece_placement(field: &str, offset: usize) -> Result<[[u64; 6]; 2], FenError> {
+    let mut bb_pieces: [[u64; 6]; 2] = [[0; 6]; 2];
+
+    let mut rank: u64 = 0;
+    let mut file: u64 = 0;
+    for (i, c) in field.char_indices() {
+        match c {
+            '/' => {
+                if file != 8 {
+                    return Err(FenError::BadRankLength { offset: offset + i });
+                }
+                if rank == 7 {
+                    return Err(FenError::BadRankCount { offset: offset + i });
+                }
+
+                rank += 1;
+                file = 0;
+            }
+            '1'..='8' => file += c.to_digit(10).unwrap() as u64,
+            _ => {
+                let Some(piece) = char_to_piece(c) else {
+                    return Err(FenError::UnexpectedChar {
+                        field: FenField::PiecePlacement,
+                        offset: offset + i,
+                        found: c,
+                    });
+                };
+                if file >= 8 {
+                    return Err(FenError::BadRankLength { offset: offset + i });
+                }
+
+                let bb_index = (7 - rank) * 8 + file;
+                bb_pieces[piece.get_color()][piece.get_type()].set_square(bb_index);
+                file += 1;
+            }
+        };
+
+        if file > 8 {
+            return Err(FenError::BadRankLength { offset: offset + i });
+        }
+    }
+
+    if file != 8 {
+        return Err(FenError::BadRankLength { offset: offset + field.len() });
+    }
+    if rank != 7 {
+        return Err(FenError::BadRankCount { offset: offset + field.len() });
+    }
+
+    Ok(bb_pieces)
+}
+
+pub fn parse_color_to_move(field: &str, offset: usize) -> Result<PieceColor, FenError> {
+    match field {
+        "w" => Ok(PieceColor::White),
+        "b" => Ok(PieceColor::Black),
+        _ => {
+            let (i, c) = field
+                .char_indices()
+                .find(|&(i, c)| i > 0 || !matches!(c, 'w' | 'b'))
+                .unwrap();
+            Err(FenError::UnexpectedChar {
+                field: FenField::ColorToMove,
+                offset: offset + i,
+                found: c,
+            })
+        }
+    }
+}
+
+/// Returns the castling rights in the bit layout used by Gamestate (K = 1, Q = 2, k = 4, q = 8) and the starting square
+/// of the rook tied to each right, indexed by [color][castle side]. Besides KQkq this accepts the file letters of
+/// Shredder-FEN and X-FEN, KQkq always refer to the outermost rook on that side of the king
+pub fn parse_castling_rights(field: &str, offset: usize, bb_pieces: &[[u64; 6]; 2]) -> Result<(u8, [[u64; 2]; 2]), FenError> {
+    let mut castling_rooks = CONSTS::STANDARD_CASTLING_ROOKS;
+    if field == "-" {
+        return Ok((0, castling_rooks));
+    }
+
+    let mut castling_rights = 0u8;
+    for (i, c) in field.char_indices() {
+        let error = FenError::UnexpectedChar {
+            field: FenField::CastlingRights,
+            offset: offset + i,
+            found: c,
+        };
+
+        let color = if c.is_ascii_uppercase() {
+            PieceColor::White
+        } else {
+            PieceColor::Black
+        };
+        let king_file = back_rank_king_file(bb_pieces, color);
+
+        let (side, rook_file) = match c.to_ascii_lowercase() {
+            'k' => (
+                CastleSide::Kingside,
+                outermost_castling_rook(bb_pieces, color, CastleSide::Kingside) % 8,
+            ),
+            'q' => (
+                CastleSide::Queenside,
+                outermost_castling_rook(bb_pieces, color, CastleSide::Queenside) % 8,
+            ),
+            'a'..='h' => {
+                let file = c.to_ascii_lowercase() as u64 - 'a' as u64;
+                match file.cmp(&king_file) {
+                    Ordering::Greater => (CastleSide::Kingside, file),
+                    Ordering::Less => (CastleSide::Queenside, file),
+                    Ordering::Equal => return Err(error),
+                }
+            }
+            _ => return Err(error),
+        };
+
+        let right = side.right_bit(color);
+        if castling_rights & right != 0 {
+            return Err(error);
+        }
+
+        castling_rights |= right;
+        castling_rooks[color][side as usize] = 56 * color as u64 + rook_file;
+    }
+
+    Ok((castling_rights, castling_rooks))
+}
+
+/// The square of the rook a K or Q castling right refers to: the outermost rook of that side of the king on the back rank,
+/// or the corner when there is none
+pub fn outermost_castling_rook(bb_pieces: &[[u64; 6]; 2], color: PieceColor, side: CastleSide) -> u64 {
+    let back_rank = 7 * color as u64;
+    let king_file = back_rank_king_file(bb_pieces, color);
+    let rooks_bb = bb_pieces[color][PieceType::Rook] & CONSTS::MASKS[back_rank as usize * 8].rank_mask;
+    let mut rook_files = (0..8u64).filter(|file| rooks_bb.contains_index(back_rank * 8 + file));
+
+    let rook_file = match side {
+        CastleSide::Kingside => rook_files.filter(|&file| file > king_file).max().unwrap_or(7),
+        CastleSide::Queenside => rook_files.find(|&file| file < king_file).unwrap_or(0),
+    };
+    back_rank * 8 + rook_file
+}
+
+/// Without a king on the back rank the castling rights can't be used anyway, the e file is just a placeholder
+fn back_rank_king_file(bb_pieces: &[[u64; 6]; 2], color: PieceColor) -> u64 {
+    let king_bb = bb_pieces[color][PieceType::King] & CONSTS::MASKS[color as usize * 56].rank_mask;
+    if king_bb != 0 {
+        king_bb.bitscan() % 8
+    } else {
+        4
+    }
+}
+
+pub fn parse_enpassant_square(field: &str, offset: usize) -> Result<Option<u64>, FenError> {
+    if field == "-" {
+        return Ok(None);
+    }
+
+    match str_to_square(field) {
+        Some(square) if square / 8 == 2 || square / 8 == 5 => Ok(Some(square)),
+        _ => Err(FenError::InvalidSquare { offset }),
+    }
+}
+
+pub fn parse_counter(field: &str, offset: usize, fen_field: FenField) -> Result<u16, FenError> {
+    if !field.chars().all(|c| c.is_ascii_digit()) {
+        return Err(FenError::InvalidNumber { field: fen_field, offset });
+    }
+
+    field.parse().map_err(|_| FenError::InvalidNumber { field: fen_field, offset })
+}
+
+pub fn char_to_piece(c: char) -> Option<Piece> {
+    let piece_type = match c.to_ascii_lowercase() {
+        'r' => PieceType::Rook,
+        'n' => PieceType::Knight,
+        'b' => PieceType::Bishop,
+        'q' => PieceType::Queen,
+        'k' => PieceType::King,
+        'p' => PieceType::Pawn,
+        _ => return None,
+    };
+    let piece_color = if c.is_ascii_uppercase() {
+        PieceColor::White
+    } else {
+        PieceColor::Black
+    };
+
+    Some(Piece::new(piece_type, piece_color))
+}
+
+pub fn piece_to_char(piece: Piece) -> char {
+    let c = match piece.get_type() {
+        PieceType::Rook => 'r',
+        PieceType::Knight => 'n',
+        PieceType::Bishop => 'b',
+        PieceType::Queen => 'q',
+        PieceType::King => 'k',
+        PieceType::Pawn => 'p',
+    };
+
+    match piece.get_color() {
+        PieceColor::White => c.to_ascii_uppercase(),
+        PieceColor::Black => c,
+    }
+}
+
+pub fn write_piece_placement(piece_matrix: &[Option<Piece>; 64]) -> String {
+    let mut res = String::with_capacity(71);
+
+    for rank in (0..8).rev() {
+        let mut empty_squares = 0;
+        for file in 0..8 {
+            match piece_matrix[rank * 8 + file] {
+                Some(piece) => {
+                    if empty_squares != 0 {
+                        res.push(char::from_digit(empty_squares, 10).unwrap());
+                        empty_squares = 0;
+                    }
+                    res.push(piece_to_char(piece));
+                }
+                None => empty_squares += 1,
+            }
+        }
+
+        if empty_squares != 0 {
+            res.push(char::from_digit(empty_squares, 10).unwrap());
+        }
+        if rank != 0 {
+            res.push('/');
+        }
+    }
+
+    res
+}
+
+/// Writes the castling rights as X-FEN: KQkq when the castling rook is the outermost one on its side of the king,
+/// the file of the rook otherwise. For standard chess this is the same as plain FEN
+pub fn write_castling_rights(castling_rights: u8, castling_rooks: &[[u64; 2]; 2], bb_pieces: &[[u64; 6]; 2]) -> String {
+    let mut res = String::new();
+
+    for color in [PieceColor::White, PieceColor::Black] {
+        for side in CastleSide::BOTH {
+            if castling_rights & side.right_bit(color) == 0 {
+                continue;
+            }
+
+            let rook_index = castling_rooks[color][side as usize];
+            let back_rank_mask = CONSTS::MASKS[rook_index as usize].rank_mask;
+            let outer_squares = match side {
+                CastleSide::Kingside => (u64::MAX << rook_index) << 1,
+                CastleSide::Queenside => (1u64 << rook_index) - 1,
+            };
+
+            let c = if bb_pieces[color][PieceType::Rook] & back_rank_mask & outer_squares != 0 {
+                (b'a' + (rook_index % 8) as u8) as char
+            } else if side == CastleSide::Kingside {
+                'k'
+            } else {
+                'q'
+            };
+
+            res.push(match color {
+                PieceColor::White => c.to_ascii_uppercase(),
+                PieceColor::Black => c,
+            });
+        }
+    }
+
+    if res.is_empty() {
+        String::from("-")
+    } else {
+        res
+    }
+}
+
+pub fn write_enpassant_square(enpassant_square: Option<u64>) -> String {
+    match enpassant_square {
+        Some(square) => square_to_str(square),
+        None => String::from("-"),
+    }
+}
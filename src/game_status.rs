@@ -0,0 +1,38 @@
+use std::fmt;
+
+use crate::piece::PieceColor;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GameStatus {
+    Ongoing,
+    Checkmate(PieceColor), // winner
+    Stalemate,
+    FiftyMove, // claimed
+    SeventyFiveMove,
+    ThreefoldRepetition, // claimed
+    FivefoldRepetition,
+    InsufficientMaterial,
+}
+
+impl GameStatus {
+    #[inline]
+    pub fn is_over(self) -> bool {
+        self != GameStatus::Ongoing
+    }
+}
+
+impl fmt::Display for GameStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameStatus::Ongoing => write!(f, "Ongoing"),
+            GameStatus::Checkmate(PieceColor::White) => write!(f, "Checkmate, white wins"),
+            GameStatus::Checkmate(PieceColor::Black) => write!(f, "Checkmate, black wins"),
+            GameStatus::Stalemate => write!(f, "Draw by stalemate"),
+            GameStatus::FiftyMove => write!(f, "Draw by the fifty-move rule"),
+            GameStatus::SeventyFiveMove => write!(f, "Draw by the seventy-five-move rule"),
+            GameStatus::ThreefoldRepetition => write!(f, "Draw by threefold repetition"),
+            GameStatus::FivefoldRepetition => write!(f, "Draw by fivefold repetition"),
+            GameStatus::InsufficientMaterial => write!(f, "Draw by insufficient material"),
+        }
+    }
+}
@@ -1,36 +1,52 @@
-use crate::{str_to_square, Piece, PieceColor};
+use crate::{material::MaterialSignature, psqt::Score, Piece, PieceColor};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CastleSide {
+    Kingside,
+    Queenside,
+}
+
+impl CastleSide {
+    pub const BOTH: [CastleSide; 2] = [CastleSide::Kingside, CastleSide::Queenside];
+
+    /// The bit of this castling right in the rights byte (K = 1, Q = 2, k = 4, q = 8)
+    #[inline]
+    pub fn right_bit(self, color: PieceColor) -> u8 {
+        1 << (2 * color as u8 + self as u8)
+    }
+}
 
 #[derive(Copy, Clone, Debug)]
 pub struct Gamestate {
     pub last_piece_captured: Option<Piece>,
     pub enpassant_square: Option<u64>,
     castling_rights: u8,
+    pub halfmove_clock: u16,
+    pub fullmove_number: u16,
+    pub plies_from_null: u16,
+    pub hash: u64,
+    pub material: [Score; 2], // [color], kept up to date by make_move like the hash
+    pub psqt: [Score; 2],
+    pub phase: u8,
+    pub material_signature: MaterialSignature,
+    pub pawn_hash: u64,
 }
 
 impl Gamestate {
-    pub fn new(castling_rights_str: &str, enpassant_square_str: &str) -> Gamestate {
-        let mut castling_rights = 0u8;
-        for c in castling_rights_str.chars() {
-            match c {
-                'K' => castling_rights += 1,
-                'Q' => castling_rights += 2,
-                'k' => castling_rights += 4,
-                'q' => castling_rights += 8,
-                '-' => break,
-                _ => panic!("unrecognized character in FEN castling rights parsing"),
-            };
-        }
-
-        let enpassant_square = if enpassant_square_str == "-" {
-            None
-        } else {
-            Some(str_to_square(enpassant_square_str))
-        };
-
+    pub fn new(castling_rights: u8, enpassant_square: Option<u64>, halfmove_clock: u16, fullmove_number: u16) -> Gamestate {
         Gamestate {
             last_piece_captured: None,
             enpassant_square,
             castling_rights,
+            halfmove_clock,
+            fullmove_number,
+            plies_from_null: halfmove_clock,
+            hash: 0,
+            material: [Score::ZERO; 2],
+            psqt: [Score::ZERO; 2],
+            phase: 0,
+            material_signature: MaterialSignature::EMPTY,
+            pawn_hash: 0,
         }
     }
 
@@ -45,54 +61,63 @@ impl Gamestate {
     }
 
     #[inline]
-    pub fn can_black_castle_queenside(self) -> bool {
-        self.castling_rights & 8 != 0
+    pub fn get_halfmove_clock(self) -> u16 {
+        self.halfmove_clock
     }
 
     #[inline]
-    pub fn can_black_castle_kingside(self) -> bool {
-        self.castling_rights & 4 != 0
+    pub fn get_fullmove_number(self) -> u16 {
+        self.fullmove_number
     }
 
     #[inline]
-    pub fn can_white_castle_queenside(self) -> bool {
-        self.castling_rights & 2 != 0
+    pub fn get_plies_from_null(self) -> u16 {
+        self.plies_from_null
     }
 
     #[inline]
-    pub fn can_white_castle_kingside(self) -> bool {
-        self.castling_rights & 1 != 0
+    pub fn get_hash(self) -> u64 {
+        self.hash
     }
 
     #[inline]
-    pub fn can_castle_kingside(self, color_to_move: PieceColor) -> bool {
-        match color_to_move {
-            PieceColor::White => self.can_white_castle_kingside(),
-            PieceColor::Black => self.can_black_castle_kingside(),
-        }
+    pub fn get_material(self, color: PieceColor) -> Score {
+        self.material[color]
     }
 
     #[inline]
-    pub fn can_castle_queenside(self, color_to_move: PieceColor) -> bool {
-        match color_to_move {
-            PieceColor::White => self.can_white_castle_queenside(),
-            PieceColor::Black => self.can_black_castle_queenside(),
-        }
+    pub fn get_psqt(self, color: PieceColor) -> Score {
+        self.psqt[color]
     }
 
     #[inline]
-    pub fn remove_castle_kingside(&mut self, color_to_move: PieceColor) {
-        match color_to_move {
-            PieceColor::White => self.castling_rights &= !(1),
-            PieceColor::Black => self.castling_rights &= !(4),
-        }
+    pub fn get_phase(self) -> u8 {
+        self.phase
     }
 
     #[inline]
-    pub fn remove_castle_queenside(&mut self, color_to_move: PieceColor) {
-        match color_to_move {
-            PieceColor::White => self.castling_rights &= !(2),
-            PieceColor::Black => self.castling_rights &= !(8),
-        }
+    pub fn get_material_signature(self) -> MaterialSignature {
+        self.material_signature
+    }
+
+    #[inline]
+    pub fn get_pawn_hash(self) -> u64 {
+        self.pawn_hash
+    }
+
+    #[inline]
+    pub fn get_castling_rights(self) -> u8 {
+        self.castling_rights
+    }
+
+    #[inline]
+    pub fn can_castle(self, color_to_move: PieceColor, side: CastleSide) -> bool {
+        self.castling_rights & side.right_bit(color_to_move) != 0
+    }
+
+    /// Keeps only the rights set in the mask
+    #[inline]
+    pub fn retain_castling_rights(&mut self, castling_rights_mask: u8) {
+        self.castling_rights &= castling_rights_mask;
     }
 }
@@ -1,4 +1,4 @@
-use crate::{board::Board, chess_move::Move, move_list::MoveList, piece::*};
+use crate::{board::Board, chess_move::Move, game_status::GameStatus, move_list::MoveList, piece::*};
 use macroquad::prelude::*;
 
 pub struct Gui {
@@ -52,6 +52,13 @@ impl Gui {
 
         self.draw_bg();
 
+        let color_to_move = board.get_color_to_move();
+        if board.is_in_check() {
+            let king_index = board.get_pieces_bb()[color_to_move][PieceType::King].trailing_zeros() as u64;
+            self.draw_check(king_index, board.attackers_to(king_index, !color_to_move));
+        }
+        self.draw_pinned(board.pinned_pieces(color_to_move));
+
         if self.moving_piece.is_some() {
             self.draw_moves(board.get_legal_moves(), self.moving_piece_index);
         }
@@ -63,6 +70,23 @@ impl Gui {
         }
     }
 
+    pub fn draw_status(&self, status: GameStatus) {
+        const BANNER_COLOR: Color = Color::new(0.0, 0.0, 0.0, 0.6);
+        const FONT_SIZE: f32 = 48.0;
+
+        let text = status.to_string();
+        let text_size = measure_text(&text, None, FONT_SIZE as u16, 1.0);
+
+        draw_rectangle(0.0, (self.window_h - FONT_SIZE * 2.0) / 2.0, self.window_w, FONT_SIZE * 2.0, BANNER_COLOR);
+        draw_text(
+            &text,
+            (self.window_w - text_size.width) / 2.0,
+            (self.window_h + text_size.height) / 2.0,
+            FONT_SIZE,
+            WHITE,
+        );
+    }
+
     fn draw_bg(&self) {
         const LIGHT_SQUARE_COLOR: Color = Color::new(0.945, 0.851, 0.753, 1.0);
         const DARK_SQUARE_COLOR: Color = Color::new(0.663, 0.478, 0.396, 1.0);
@@ -81,6 +105,29 @@ impl Gui {
         }
     }
 
+    fn draw_check(&self, king_index: u64, checkers_bb: u64) {
+        const CHECK_SQUARE_COLOR: Color = Color::new(0.9, 0.1, 0.1, 0.7);
+        const CHECKER_SQUARE_COLOR: Color = Color::new(0.9, 0.1, 0.1, 0.35);
+
+        self.draw_squares(1 << king_index, CHECK_SQUARE_COLOR);
+        self.draw_squares(checkers_bb, CHECKER_SQUARE_COLOR);
+    }
+
+    fn draw_pinned(&self, pinned_bb: u64) {
+        const PINNED_SQUARE_COLOR: Color = Color::new(0.2, 0.3, 0.9, 0.35);
+
+        self.draw_squares(pinned_bb, PINNED_SQUARE_COLOR);
+    }
+
+    fn draw_squares(&self, squares_bb: u64, color: Color) {
+        let square_w = self.window_w / 8.0;
+        let square_h = self.window_h / 8.0;
+
+        for index in (0..64).filter(|index| squares_bb & (1 << index) != 0) {
+            draw_rectangle((index % 8) as f32 * square_w, (7 - index / 8) as f32 * square_h, square_w, square_h, color);
+        }
+    }
+
     fn draw_moves(&self, moves: &MoveList, from: u64) {
         const MOVE_START_SQUARE_COLOR: Color = Color::new(1.0, 0.0, 0.0, 0.549);
         const MOVE_LAND_SQUARE_COLOR: Color = Color::new(1.0, 0.584, 0.110, 0.745);
@@ -163,6 +210,30 @@ impl Gui {
         }
     }
 
+    /// Backspace takes back moves until the player is to move again, so the reply of the engine is taken back too.
+    /// Returns whether a move was taken back
+    pub fn handle_undo(&mut self, board: &mut Board, player_color: PieceColor) -> bool {
+        if !is_key_pressed(KeyCode::Backspace) || board.undo().is_none() {
+            return false;
+        }
+
+        if board.get_color_to_move() != player_color {
+            board.undo();
+        }
+        board.generate_legal_moves();
+        self.moving_piece = None;
+        true
+    }
+
+    /// D claims a draw by the fifty-move rule or threefold repetition when the player is to move
+    pub fn handle_draw_claim(&self, board: &Board, player_color: PieceColor) -> Option<GameStatus> {
+        if !is_key_pressed(KeyCode::D) || board.get_color_to_move() != player_color {
+            return None;
+        }
+
+        board.claimable_draw()
+    }
+
     fn mouse_pos_to_index(&self, mouse_pos: (f32, f32)) -> u64 {
         (8.0 - mouse_pos.1 * 8.0 / self.window_h) as u64 * 8 + (mouse_pos.0 * 8.0 / self.window_w) as u64
     }
@@ -0,0 +1,275 @@
+use std::sync::LazyLock;
+
+use crate::bitboard::Bitmanip;
+
+/// Where the attacks of one square start in the shared table and how an occupancy is turned into an index
+#[derive(Copy, Clone, Default)]
+struct Entry {
+    mask: u64,
+    magic: u64,
+    shift: u32,
+    offset: usize,
+}
+
+impl Entry {
+    #[cfg(all(feature = "pext", target_arch = "x86_64", target_feature = "bmi2"))]
+    #[inline]
+    fn index(&self, occupied_bb: u64) -> usize {
+        // SAFETY: the cfg above only compiles this when the target has BMI2
+        self.offset + unsafe { std::arch::x86_64::_pext_u64(occupied_bb, self.mask) } as usize
+    }
+
+    #[cfg(not(all(feature = "pext", target_arch = "x86_64", target_feature = "bmi2")))]
+    #[inline]
+    fn index(&self, occupied_bb: u64) -> usize {
+        self.offset + ((occupied_bb & self.mask).wrapping_mul(self.magic) >> self.shift) as usize
+    }
+}
+
+/// Attacks of one slider type for every square and every relevant occupancy
+struct SliderTable {
+    entries: [Entry; 64],
+    attacks: Vec<u64>,
+}
+
+const ROOK_DIRECTIONS: [(i64, i64); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i64, i64); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+// Found once by trying sparse random numbers until one had no destructive collisions, that search takes too long to
+// run at every startup. SliderTable::new checks them again while filling the tables
+const ROOK_MAGICS: [u64; 64] = [
+    0x1080004008801020,
+    0x0840092002c03000,
+    0x1900200010400900,
+    0x0880100008000480,
+    0x4200100420080200,
+    0x8100020100080400,
+    0x0200040110886200,
+    0x0200008040220411,
+    0x0404800084400220,
+    0x0000401000402000,
+    0x0086001081220440,
+    0x0408800800100280,
+    0x000a001201040820,
+    0x8848800200840080,
+    0x4001000100040200,
+    0x0442000102105084,
+    0x9080010020804100,
+    0x0040404000201009,
+    0x0000808010002009,
+    0x2200090021d00100,
+    0x0008008008040080,
+    0x0004004002010040,
+    0x0011040008015042,
+    0x00000a0001768104,
+    0x0000800080204009,
+    0x2010004140002001,
+    0x9800200280100080,
+    0x1000100080080080,
+    0x0442000a00049020,
+    0x2100040080020080,
+    0x0800120400900148,
+    0x0010040a00128541,
+    0x2800804000800030,
+    0x1010002000400041,
+    0x4000200011004100,
+    0x0610008410800800,
+    0x0400802402800800,
+    0xc100020080800400,
+    0x0002000802000401,
+    0x0182085882000401,
+    0x0220204000808000,
+    0x2860100040024022,
+    0x0001002004110040,
+    0x99101042000a0020,
+    0x0004080004008080,
+    0x0010040002008080,
+    0x2012004881020004,
+    0x8300842444820011,
+    0x0088403882010200,
+    0x0820400080210100,
+    0x0110910040a00300,
+    0x0801100280080480,
+    0x0242009008200600,
+    0x1002000489500200,
+    0x0040800200010080,
+    0x0091800041000080,
+    0x0000209300488001,
+    0x04c1002414824001,
+    0x020020000b001041,
+    0x7000100004200901,
+    0x8002002004100802,
+    0x30010002084c0007,
+    0x0888221800813004,
+    0x4000002840840112,
+];
+
+const BISHOP_MAGICS: [u64; 64] = [
+    0x10102002004a1420,
+    0x8020040400584008,
+    0x10510800811201c8,
+    0x5204042080000088,
+    0x2204106880000002,
+    0x1401042004000000,
+    0x0400880410042004,
+    0x0028208200a02020,
+    0x1500241990010e00,
+    0x8001200182020a40,
+    0x40004101030b0000,
+    0x8002041042000100,
+    0x4010011041020038,
+    0x0000010421044000,
+    0x1500210808020a00,
+    0x8000088400880520,
+    0x0405004010040100,
+    0x1005823210040108,
+    0x2708008102040011,
+    0x4048200404009100,
+    0x0018104101400024,
+    0x0003000601190101,
+    0x8004803108491000,
+    0x8014241200820800,
+    0x0006e080100c3040,
+    0x0501044a11041800,
+    0x9020300008004045,
+    0x0894080000220040,
+    0x1001010083104000,
+    0x5004030040900080,
+    0x000400422c012400,
+    0x0002128698404812,
+    0x1010108404900440,
+    0x0928021182084100,
+    0x2006080409020024,
+    0x1010202020180080,
+    0xa010008200202200,
+    0x2098015100019004,
+    0x0002041440810811,
+    0x802a02020000b098,
+    0x0009015090004060,
+    0x4000821082081001,
+    0x0100210040420800,
+    0x0800004010488a00,
+    0x2000081104004040,
+    0x4c8e029015000082,
+    0x0420340322224842,
+    0x1298260043400210,
+    0x0000822802400008,
+    0x00008a0101600000,
+    0x3040003412080021,
+    0x3040290220884800,
+    0x4a1500401041004a,
+    0x8010200282020781,
+    0x0020203142209091,
+    0x0070300600902110,
+    0x0040808800b62048,
+    0x0000810400c44420,
+    0x00080400440c0441,
+    0x8340080020840411,
+    0x0000000104208200,
+    0x0000800810d00080,
+    0x0400530411080200,
+    0x4040702400932244,
+];
+
+static ROOK_TABLE: LazyLock<SliderTable> = LazyLock::new(|| SliderTable::new(ROOK_DIRECTIONS, &ROOK_MAGICS));
+static BISHOP_TABLE: LazyLock<SliderTable> = LazyLock::new(|| SliderTable::new(BISHOP_DIRECTIONS, &BISHOP_MAGICS));
+
+#[inline]
+pub fn rook_attacks(index: u64, occupied_bb: u64) -> u64 {
+    ROOK_TABLE.attacks(index, occupied_bb)
+}
+
+#[inline]
+pub fn bishop_attacks(index: u64, occupied_bb: u64) -> u64 {
+    BISHOP_TABLE.attacks(index, occupied_bb)
+}
+
+/// Builds both tables now instead of on the first lookup, so the first search doesn't pay for it
+pub fn init() {
+    LazyLock::force(&ROOK_TABLE);
+    LazyLock::force(&BISHOP_TABLE);
+}
+
+impl SliderTable {
+    #[inline]
+    fn attacks(&self, index: u64, occupied_bb: u64) -> u64 {
+        let entry = &self.entries[index as usize];
+        self.attacks[entry.index(occupied_bb)]
+    }
+
+    fn new(directions: [(i64, i64); 4], magics: &[u64; 64]) -> SliderTable {
+        let mut entries = [Entry::default(); 64];
+        let mut attacks = Vec::new();
+
+        for (square, entry) in entries.iter_mut().enumerate() {
+            let mask = relevant_occupancy_mask(square as u64, directions);
+            let bits = mask.count_ones();
+
+            entry.mask = mask;
+            entry.magic = magics[square];
+            entry.shift = 64 - bits;
+            entry.offset = attacks.len();
+
+            let mut filled = vec![false; 1 << bits];
+            attacks.resize(attacks.len() + (1 << bits), 0);
+
+            // every subset of the mask, enumerated with the Carry-Rippler trick
+            let mut occupied_bb = 0u64;
+            loop {
+                let square_attacks = ray_attacks(square as u64, occupied_bb, directions);
+                let index = entry.index(occupied_bb);
+                assert!(
+                    !filled[index - entry.offset] || attacks[index] == square_attacks,
+                    "magic of square {square} maps occupancies with different attacks to the same entry"
+                );
+                filled[index - entry.offset] = true;
+                attacks[index] = square_attacks;
+
+                occupied_bb = occupied_bb.wrapping_sub(mask) & mask;
+                if occupied_bb == 0 {
+                    break;
+                }
+            }
+        }
+
+        SliderTable { entries, attacks }
+    }
+}
+
+/// Squares whose occupancy changes the attacks from square: the rays without their last square
+fn relevant_occupancy_mask(square: u64, directions: [(i64, i64); 4]) -> u64 {
+    let mut res = 0u64;
+
+    for (file_step, rank_step) in directions {
+        let mut file = (square % 8) as i64 + file_step;
+        let mut rank = (square / 8) as i64 + rank_step;
+        while (0..8).contains(&(file + file_step)) && (0..8).contains(&(rank + rank_step)) {
+            res.set_square((rank * 8 + file) as u64);
+            file += file_step;
+            rank += rank_step;
+        }
+    }
+
+    res
+}
+
+/// Walks every ray square by square up to and including the first blocker, slow but obviously right
+fn ray_attacks(square: u64, occupied_bb: u64, directions: [(i64, i64); 4]) -> u64 {
+    let mut res = 0u64;
+
+    for (file_step, rank_step) in directions {
+        let mut file = (square % 8) as i64 + file_step;
+        let mut rank = (square / 8) as i64 + rank_step;
+        while (0..8).contains(&file) && (0..8).contains(&rank) {
+            let target = (rank * 8 + file) as u64;
+            res.set_square(target);
+            if occupied_bb.contains_index(target) {
+                break;
+            }
+            file += file_step;
+            rank += rank_step;
+        }
+    }
+
+    res
+}
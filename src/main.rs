@@ -1,17 +1,29 @@
 mod bitboard;
 mod board;
+mod chess960;
 mod chess_move;
 mod consts;
 mod engine;
+mod epd;
+mod fen;
+mod game_status;
 mod gamestate;
 mod gui;
+mod magic;
+mod material;
 mod move_generation;
 mod move_list;
 mod piece;
+mod psqt;
+mod san;
+mod validation;
+mod zobrist;
 
 use std::time::Instant;
 
 use crate::board::Board;
+use crate::epd::Epd;
+use crate::fen::FenError;
 use crate::gui::Gui;
 
 use macroquad::prelude::*;
@@ -23,26 +35,132 @@ const _TEST_FEN_STRING: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - ";
 
 const ENGINE_DEPTH: u8 = 7;
 
-#[macroquad::main("Chess")]
+fn main() {
+    if cfg!(feature = "magic") {
+        magic::init();
+    }
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [flag, path] if flag == "--epd" => run_epd_suite(path),
+        [flag, depth, position_args @ ..] if flag == "--perft" => match depth.parse::<u8>() {
+            Ok(depth) => _perft_test(depth, &mut starting_board(position_args)),
+            Err(_) => exit_with_usage(),
+        },
+        _ => macroquad::Window::new("Chess", play(starting_board(&args))),
+    }
+}
+
+/// The position given on the command line, `--fen <FEN>` starts from that position instead of the initial one and
+/// `--chess960 <index>` from the Chess960 starting position with that number (0..960).
+/// A FEN that doesn't parse is reported with a marker under the character where the problem was found
+fn starting_board(args: &[String]) -> Board {
+    match args {
+        [] => Board::new(_INITIAL_FEN_STRING),
+        [flag, fen] if flag == "--fen" => Board::from_fen(fen).unwrap_or_else(|e| exit_with_fen_error(fen, e)),
+        [flag, index] if flag == "--chess960" => match index.parse::<u16>() {
+            Ok(index) if index < 960 => Board::chess960(index),
+            _ => exit_with_usage(),
+        },
+        _ => exit_with_usage(),
+    }
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("usage: Chess_Rust_Bitmap [--perft <depth>] [--fen <FEN> | --chess960 <index>] | --epd <file>");
+    std::process::exit(2);
+}
 
-async fn main() {
+fn exit_with_fen_error(fen: &str, error: FenError) -> ! {
+    eprintln!("invalid FEN: {error}");
+    eprintln!("  {fen}");
+    let field = error.field().map_or(String::new(), |field| field.to_string());
+    eprintln!("  {}^ {field}", " ".repeat(error.offset()));
+    std::process::exit(1);
+}
+
+/// Searches every position of an EPD test suite and reports the ones where the engine plays a move outside bm or
+/// one listed in am. Records that don't parse are reported and counted as failures
+fn run_epd_suite(path: &str) {
+    let contents = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("can't read {path}: {e}");
+        std::process::exit(1);
+    });
+
+    let records = Epd::parse_all(&contents);
+    let record_count = records.len();
+    let mut solved = 0;
+
+    for (record_index, record) in records.into_iter().enumerate() {
+        let mut epd = match record {
+            Ok(epd) => epd,
+            Err(e) => {
+                println!("record {}: {e}", record_index + 1);
+                continue;
+            }
+        };
+        let id = epd.id().map_or_else(|| format!("record {}", record_index + 1), str::to_string);
+
+        let Some((found, _)) = engine::search(&mut epd.board) else {
+            println!("{id}: no legal move");
+            continue;
+        };
+        let found_san = san::move_to_san(&mut epd.board, found);
+
+        let best_moves = epd.best_moves();
+        if (best_moves.is_empty() || best_moves.contains(&found)) && !epd.avoid_moves().contains(&found) {
+            solved += 1;
+            println!("{id}: {found_san} ok");
+        } else {
+            println!("{id}: {found_san} failed");
+            println!("  {}", epd.write());
+        }
+    }
+
+    println!("solved {solved}/{record_count}");
+}
+
+async fn play(mut board: Board) {
     request_new_screen_size(900.0, 900.0);
     let mut gui = Gui::new().await;
-    let mut board = Board::new(_INITIAL_FEN_STRING);
     let cpu_color = !board.get_color_to_move();
+    let starting_board = board.clone();
+    let mut game_printed = false;
+    let mut claimed_draw = None;
 
     //_perft_test(8, &mut board);
 
     loop {
-        if board.get_color_to_move() == cpu_color {
+        if gui.handle_undo(&mut board, !cpu_color) {
+            claimed_draw = None;
+        }
+
+        let status = claimed_draw.unwrap_or_else(|| board.status());
+
+        if board.get_color_to_move() == cpu_color && !status.is_over() {
             engine::play_next_move(&mut board);
             board.generate_legal_moves();
         }
 
-        gui.handle_input(&mut board);
+        if !status.is_over() {
+            claimed_draw = gui.handle_draw_claim(&board, !cpu_color);
+            if claimed_draw.is_none() {
+                gui.handle_input(&mut board);
+            }
+        }
 
         gui.draw(&board);
 
+        if status.is_over() {
+            gui.draw_status(status);
+
+            if !game_printed {
+                println!("{status}: {}", san::moves_to_san(&mut starting_board.clone(), board.history()));
+            }
+        }
+        // taking back moves after the end of the game prints it again once it is over
+        game_printed = status.is_over();
+
         next_frame().await
     }
 }
@@ -58,7 +176,7 @@ fn _perft_test(max_depth: u8, board: &mut Board) {
         println!(
             "Depth: {:>2} | Nodes: {:>12} | Time: {}s",
             i,
-            _perft_test_r(i, board, i),
+            _perft_test_parallel(i, board),
             now.elapsed().as_secs_f32()
         );
     }
@@ -85,8 +203,8 @@ fn _perft_test_r(depth: u8, board: &mut Board, max_depth: u8) -> u128 {
             if depth == max_depth {
                 println!(
                     "{}{} : {}",
-                    _square_to_str(m.get_from()),
-                    _square_to_str(m.get_to()),
+                    square_to_str(m.get_from()),
+                    square_to_str(m.get_to()),
                     positions_after_this_move
                 );
             }
@@ -98,15 +216,1172 @@ fn _perft_test_r(depth: u8, board: &mut Board, max_depth: u8) -> u128 {
     res
 }
 
-fn _square_to_str(index: u64) -> String {
+/// Same count as _perft_test_r, with the root moves split between one thread per core. Every thread rebuilds its
+/// own board from a Position snapshot and plays its share of moves on it
+fn _perft_test_parallel(depth: u8, board: &Board) -> u128 {
+    if depth <= 1 {
+        return _perft_test_r(depth, &mut board.clone(), depth);
+    }
+
+    let legal_moves: Vec<_> = generate_legal_moves(board).iter().collect();
+    let position = board.to_position();
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = legal_moves.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = legal_moves
+            .chunks(chunk_size)
+            .map(|moves| {
+                scope.spawn(move || {
+                    let mut board = Board::from_position(&position);
+                    let mut res = 0;
+                    for &m in moves {
+                        board.make_move(m);
+                        res += _perft_test_r(depth - 1, &mut board, depth);
+                        board.unmake_move(m);
+                    }
+                    res
+                })
+            })
+            .collect();
+
+        handles.into_iter().map(|handle| handle.join().unwrap()).sum()
+    })
+}
+
+fn square_to_str(index: u64) -> String {
     let y = index / 8;
     let x = index % 8;
 
-    (('a' as u8 + x as u8) as char).to_string() + &((y as u8 + '1' as u8) as char).to_string()
+    ((b'a' + x as u8) as char).to_string() + &((y as u8 + b'1') as char).to_string()
 }
 
-fn str_to_square(name: &str) -> u64 {
+fn str_to_square(name: &str) -> Option<u64> {
     let mut chars = name.chars();
+    let file = chars.next()?;
+    let rank = chars.next()?;
+
+    if chars.next().is_some() || !('a'..='h').contains(&file) || !('1'..='8').contains(&rank) {
+        return None;
+    }
+
+    Some((file as u64 - 'a' as u64) + (rank as u64 - '1' as u64) * 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Node counts of the positions from the Chess Programming Wiki perft results page, one entry per depth starting at 1
+    const PERFT_POSITIONS: [(&str, &[u128]); 9] = [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            &[20, 400, 8902, 197281, 4865609],
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            &[48, 2039, 97862, 4085603],
+        ),
+        ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", &[14, 191, 2812, 43238, 674624]),
+        (
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            &[6, 264, 9467, 422333],
+        ),
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            &[44, 1486, 62379, 2103487],
+        ),
+        (
+            "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+            &[46, 2079, 89890, 3894594],
+        ),
+        // Chess960 positions with Shredder-FEN castling rights
+        ("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9", &[21, 528, 12189]),
+        ("2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9", &[21, 807, 18002]),
+        ("b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9", &[20, 479, 10471]),
+    ];
+
+    #[test]
+    fn perft() {
+        for (fen, node_counts) in PERFT_POSITIONS {
+            let mut board = Board::new(fen);
+            let fen = board.to_fen();
+            for (depth, &nodes) in (1..).zip(node_counts) {
+                assert_eq!(_perft_test_r(depth, &mut board, 0), nodes, "{fen} at depth {depth}");
+            }
+            assert_eq!(board.to_fen(), fen, "{fen} changed after perft");
+        }
+    }
+
+    #[test]
+    fn parallel_perft_matches_perft() {
+        for (fen, _) in &PERFT_POSITIONS[..6] {
+            let mut board = Board::new(fen);
+            for depth in 1..=3 {
+                assert_eq!(
+                    _perft_test_parallel(depth, &board),
+                    _perft_test_r(depth, &mut board, 0),
+                    "{fen} at depth {depth}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn perft_is_symmetric() {
+        // the score is from the side to move, from white's side it changes sign when the colors are swapped
+        fn white_score(board: &Board) -> i32 {
+            let score = board.get_tapered_score();
+            if board.get_color_to_move() == PieceColor::White {
+                score
+            } else {
+                -score
+            }
+        }
+
+        for (fen, node_counts) in PERFT_POSITIONS {
+            let board = Board::new(fen);
+            let mut mirrored = board.mirrored();
+            assert_eq!(white_score(&mirrored), -white_score(&board), "{fen}");
+
+            // flipping drops the castling rights, so the flipped board is compared with itself flipped back
+            let mut flipped = board.flipped_horizontally();
+            let mut unflipped = flipped.flipped_horizontally();
+            let has_castling_rights = fen.split(' ').nth(2) != Some("-");
+
+            for (depth, &nodes) in (1..=3).zip(node_counts) {
+                assert_eq!(_perft_test_r(depth, &mut mirrored, 0), nodes, "{fen} mirrored at depth {depth}");
+                let flipped_nodes = _perft_test_r(depth, &mut flipped, 0);
+                assert_eq!(flipped_nodes, _perft_test_r(depth, &mut unflipped, 0), "{fen} flipped at depth {depth}");
+                if !has_castling_rights {
+                    assert_eq!(flipped_nodes, nodes, "{fen} flipped at depth {depth}");
+                }
+            }
+        }
+    }
+
+    /// Calls visit on every position reached from board in at most depth moves, board itself included
+    fn for_each_node(board: &mut Board, depth: u8, visit: &mut impl FnMut(&mut Board)) {
+        visit(board);
+        if depth == 0 {
+            return;
+        }
+        for m in generate_legal_moves(board).iter() {
+            board.make_move(m);
+            for_each_node(board, depth - 1, visit);
+            board.unmake_move(m);
+        }
+    }
+
+    /// Plays space separated SAN moves
+    fn play(board: &mut Board, moves: &str) {
+        for san in moves.split_whitespace() {
+            let m = san::parse_san(board, san).unwrap_or_else(|e| panic!("{san} in {}: {e}", board.to_fen()));
+            board.make_move(m);
+        }
+    }
+
+    #[test]
+    fn fen_round_trips_after_every_move() {
+        for (fen, _) in PERFT_POSITIONS {
+            for_each_node(&mut Board::new(fen), 2, &mut |board| {
+                let fen = board.to_fen();
+                let reloaded = Board::from_fen(&fen).unwrap();
+                assert_eq!(reloaded.to_fen(), fen);
+                assert_eq!(reloaded.get_hash(), board.get_hash(), "{fen}");
+            });
+        }
+    }
+
+    #[test]
+    fn staged_generation_splits_legal_moves() {
+        use move_generation::{generate_moves, GenType};
+
+        fn move_keys(moves: &[chess_move::Move]) -> Vec<(u64, u64, Option<u8>)> {
+            let mut keys: Vec<_> = moves
+                .iter()
+                .map(|m| {
+                    (
+                        m.get_from(),
+                        m.get_to(),
+                        m.is_promotion().then(|| m.get_promotion_type() as u8),
+                    )
+                })
+                .collect();
+            keys.sort();
+            keys
+        }
+
+        fn check(board: &mut Board, depth: u8) {
+            let legal_moves: Vec<_> = generate_legal_moves(board).iter().collect();
+            let mut staged: Vec<_> = generate_moves(board, GenType::Captures).iter().collect();
+            assert!(staged
+                .iter()
+                .all(|m| m.is_promotion() || m.is_enpassant() || board.get_piece_at(m.get_to()).is_some()));
+            staged.extend(generate_moves(board, GenType::Quiets).iter());
+            assert_eq!(move_keys(&staged), move_keys(&legal_moves), "{}", board.to_fen());
+
+            let evasions: Vec<_> = generate_moves(board, GenType::Evasions).iter().collect();
+            let expected = if board.is_in_check() { legal_moves.as_slice() } else { &[] };
+            assert_eq!(move_keys(&evasions), move_keys(expected), "{}", board.to_fen());
+
+            if depth > 0 {
+                for m in legal_moves {
+                    board.make_move(m);
+                    check(board, depth - 1);
+                    board.unmake_move(m);
+                }
+            }
+        }
+
+        for (fen, _) in PERFT_POSITIONS {
+            check(&mut Board::new(fen), 2);
+        }
+    }
+
+    #[test]
+    fn magic_attacks_match_hyperbola_quintessence() {
+        use move_generation::{hyperbola_bishop_attacks, hyperbola_rook_attacks};
+
+        // xorshift with a fixed seed, sparse and dense occupancies both show up by and-ing or or-ing draws
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for square in 0..64 {
+            for i in 0..2000 {
+                let occupied_bb = match i % 3 {
+                    0 => random() & random() & random(),
+                    1 => random(),
+                    _ => random() | random(),
+                };
+                assert_eq!(
+                    magic::rook_attacks(square, occupied_bb),
+                    hyperbola_rook_attacks(square, occupied_bb),
+                    "rook on {} with occupancy {occupied_bb:#018x}",
+                    square_to_str(square)
+                );
+                assert_eq!(
+                    magic::bishop_attacks(square, occupied_bb),
+                    hyperbola_bishop_attacks(square, occupied_bb),
+                    "bishop on {} with occupancy {occupied_bb:#018x}",
+                    square_to_str(square)
+                );
+            }
+        }
+    }
+
+    /// FNV-1a over whole words, enough to notice any change in a table
+    fn fingerprint(values: impl IntoIterator<Item = u64>) -> u64 {
+        values
+            .into_iter()
+            .fold(0xcbf29ce484222325, |hash, value| (hash ^ value).wrapping_mul(0x100000001b3))
+    }
+
+    /// The expected fingerprints are those of the literal tables that were checked in before the tables were computed
+    #[test]
+    fn computed_tables_match_former_literals() {
+        use consts::CONSTS;
+
+        assert_eq!(fingerprint(CONSTS::KNIGHT_TABLE), 0x4aa3570ca1c60201);
+        assert_eq!(fingerprint(CONSTS::KING_TABLE), 0xffed7993989ae3c2);
+        let masks = CONSTS::MASKS.iter().flat_map(|mask| {
+            [
+                mask.diagonal_mask_ex,
+                mask.antidiag_mask_ex,
+                mask.file_mask_ex,
+                mask.rank_mask,
+            ]
+        });
+        assert_eq!(fingerprint(masks), 0xbc2aae5abd24df85);
+        assert_eq!(
+            fingerprint(CONSTS::SQUARES_BETWEEN.iter().flatten().copied()),
+            0xc4678bb1eb319b55
+        );
+
+        // corners, edges and a central square: (square, knight attacks, king attacks)
+        let leapers = [
+            ("a1", 0x0000000000020400, 0x0000000000000302),
+            ("h1", 0x0000000000402000, 0x000000000000c040),
+            ("a8", 0x0004020000000000, 0x0203000000000000),
+            ("h8", 0x0020400000000000, 0x40c0000000000000),
+            ("d1", 0x0000000000142200, 0x0000000000001c14),
+            ("e8", 0x0044280000000000, 0x2838000000000000),
+            ("a4", 0x0000020400040200, 0x0000000302030000),
+            ("h5", 0x0040200020400000, 0x0000c040c0000000),
+            ("e4", 0x0000284400442800, 0x0000003828380000),
+        ];
+        for (square, knight, king) in leapers {
+            let index = str_to_square(square).unwrap() as usize;
+            assert_eq!(CONSTS::KNIGHT_TABLE[index], knight, "knight on {square}");
+            assert_eq!(CONSTS::KING_TABLE[index], king, "king on {square}");
+        }
+
+        // (square, diagonal, antidiagonal, file, rank)
+        let masks = [
+            ("a1", 0x8040201008040200, 0x0000000000000000, 0x0101010101010100, 0x00000000000000ff),
+            ("h1", 0x0000000000000000, 0x0102040810204000, 0x8080808080808000, 0x00000000000000ff),
+            ("h8", 0x0040201008040201, 0x0000000000000000, 0x0080808080808080, 0xff00000000000000),
+            ("e4", 0x0080402000080402, 0x0102040800204080, 0x1010101000101010, 0x00000000ff000000),
+        ];
+        for (square, diagonal, antidiagonal, file, rank) in masks {
+            let mask = &CONSTS::MASKS[str_to_square(square).unwrap() as usize];
+            assert_eq!(
+                (mask.diagonal_mask_ex, mask.antidiag_mask_ex, mask.file_mask_ex, mask.rank_mask),
+                (diagonal, antidiagonal, file, rank),
+                "masks of {square}"
+            );
+        }
+        assert_eq!(CONSTS::MASKS[1].diagonal_mask_ex, 0x0080402010080400);
+        assert_eq!(CONSTS::MASKS[1].antidiag_mask_ex, 0x0000000000000100);
+
+        let between = [
+            ("a1", "h8", 0x0040201008040200),
+            ("a8", "h1", 0x0002040810204000),
+            ("a1", "a8", 0x0001010101010100),
+            ("e1", "e4", 0x0000000000101000),
+            ("a1", "g1", 0x000000000000003e),
+            ("a1", "b1", 0),
+            ("a1", "b3", 0),
+        ];
+        for (a, b, expected) in between {
+            let (a_index, b_index) = (str_to_square(a).unwrap() as usize, str_to_square(b).unwrap() as usize);
+            assert_eq!(CONSTS::SQUARES_BETWEEN[a_index][b_index], expected, "between {a} and {b}");
+            assert_eq!(CONSTS::SQUARES_BETWEEN[b_index][a_index], expected, "between {b} and {a}");
+        }
+        assert_eq!(CONSTS::SQUARES_BETWEEN[0][9], 0);
+    }
+
+    #[test]
+    fn line_through_contains_squares_between() {
+        use consts::CONSTS;
+
+        for a in 0..64 {
+            for b in 0..64 {
+                let line = CONSTS::LINE_THROUGH[a][b];
+                if line == 0 {
+                    assert!(a == b || CONSTS::SQUARES_BETWEEN[a][b] == 0);
+                    continue;
+                }
+                let segment = CONSTS::SQUARES_BETWEEN[a][b] | 1 << a | 1 << b;
+                assert_eq!(
+                    line & segment,
+                    segment,
+                    "{} {}",
+                    square_to_str(a as u64),
+                    square_to_str(b as u64)
+                );
+                assert_eq!(line, CONSTS::LINE_THROUGH[b][a]);
+            }
+        }
+        assert_eq!(CONSTS::LINE_THROUGH[0][63], 0x8040201008040201);
+        assert_eq!(CONSTS::LINE_THROUGH[9][12], 0xff00);
+    }
+
+    #[test]
+    fn move_counters_saturate() {
+        let mut board = Board::new("4k3/8/8/8/8/8/8/4K3 b - - 65535 65535");
+        let m = board.get_legal_moves().iter().next().unwrap();
+
+        board.make_move(m);
+        assert_eq!(board.to_fen().split(' ').skip(4).collect::<Vec<_>>(), ["65535", "65535"]);
+        board.unmake_move(m);
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 65535 65535");
+    }
+
+    #[test]
+    fn knight_shuffle_repeats_the_position() {
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        assert_eq!(board.repetition_count(), 1);
+
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.repetition_count(), 2);
+        assert!(board.is_repetition() && !board.is_threefold_repetition());
+
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.repetition_count(), 3);
+        assert!(board.is_threefold_repetition());
+    }
 
-    ((chars.next().unwrap() as u32 - 'a' as u32) + (chars.next().unwrap().to_digit(10).unwrap() - 1) * 8) as u64
+    #[test]
+    fn enpassant_square_is_only_kept_when_a_pawn_can_capture() {
+        // nothing can take on e3, so the position after 1.e4 comes back once the knights return
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        play(&mut board, "e4");
+        assert_eq!(board.current_gamestate.get_enpassant_square(), None);
+        let hash = board.get_hash();
+        play(&mut board, "Nf6 Nf3 Ng8 Ng1");
+        assert_eq!(board.get_hash(), hash);
+        assert_eq!(board.repetition_count(), 2);
+
+        // the same goes for a square given in a FEN
+        let board = Board::new("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1");
+        assert_eq!(board.get_hash(), hash);
+        assert_eq!(board.to_fen(), "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1");
+
+        // after 2...d5 the e5 pawn can take on d6, the square is kept and the position doesn't repeat
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        play(&mut board, "e4 Nf6 e5 d5");
+        assert_eq!(board.current_gamestate.get_enpassant_square(), str_to_square("d6"));
+        play(&mut board, "Nf3 Nc6 Ng1 Nb8");
+        assert_eq!(board.repetition_count(), 1);
+    }
+
+    #[test]
+    fn repetition_count_resets_after_irreversible_moves() {
+        // a pawn move and a capture: only the shuffle played after them counts
+        for (fen, irreversible_move) in [
+            (_INITIAL_FEN_STRING, "e3"),
+            ("4k1n1/8/8/3p4/4P3/8/8/4K1N1 w - - 0 1", "exd5"),
+        ] {
+            let mut board = Board::new(fen);
+            play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+            assert_eq!(board.repetition_count(), 2);
+
+            play(&mut board, irreversible_move);
+            assert_eq!(board.repetition_count(), 1);
+            play(&mut board, "Nf6 Nf3 Ng8 Ng1");
+            assert_eq!(board.repetition_count(), 2, "{fen} after {irreversible_move}");
+        }
+
+        // positions before a null move don't count either, even though two null moves give back the same hash
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        board.make_null_move();
+        board.make_null_move();
+        assert_eq!(board.repetition_count(), 1);
+        board.unmake_null_move();
+        board.unmake_null_move();
+        assert_eq!(board.repetition_count(), 2);
+    }
+
+    #[test]
+    fn status_of_finished_games() {
+        use game_status::GameStatus;
+
+        let cases = [
+            (_INITIAL_FEN_STRING, GameStatus::Ongoing),
+            (
+                "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3",
+                GameStatus::Checkmate(PieceColor::Black),
+            ),
+            ("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1", GameStatus::Stalemate),
+            ("4k3/8/8/8/8/8/8/R3K3 w - - 150 105", GameStatus::SeventyFiveMove),
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", GameStatus::InsufficientMaterial),
+            // a mate given on the move that reaches 75 moves still counts
+            ("R6k/8/6K1/8/8/8/8/8 b - - 150 105", GameStatus::Checkmate(PieceColor::White)),
+        ];
+        for (fen, status) in cases {
+            assert_eq!(Board::new(fen).status(), status, "{fen}");
+        }
+    }
+
+    #[test]
+    fn draws_that_must_be_claimed() {
+        use game_status::GameStatus;
+
+        let board = Board::new("4k3/8/8/8/8/8/8/R3K3 w - - 100 80");
+        assert_eq!(board.status(), GameStatus::Ongoing);
+        assert_eq!(board.claimable_draw(), Some(GameStatus::FiftyMove));
+        assert_eq!(Board::new("4k3/8/8/8/8/8/8/R3K3 w - - 99 80").claimable_draw(), None);
+
+        // the third occurrence can be claimed, the fifth ends the game
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.claimable_draw(), None);
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.claimable_draw(), Some(GameStatus::ThreefoldRepetition));
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.status(), GameStatus::Ongoing);
+        play(&mut board, "Nf3 Nf6 Ng1 Ng8");
+        assert_eq!(board.status(), GameStatus::FivefoldRepetition);
+    }
+
+    #[test]
+    fn insufficient_material() {
+        let draws = [
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/8/4KN2 w - - 0 1",
+            // both bishops on light squares
+            "2b1k3/8/8/8/8/8/8/4KB2 w - - 0 1",
+        ];
+        for fen in draws {
+            assert!(Board::new(fen).has_insufficient_material(), "{fen}");
+        }
+
+        let not_draws = [
+            // bishops on opposite colors, a mate is possible with the help of the other side
+            "3bk3/8/8/8/8/8/8/4KB2 w - - 0 1",
+            "4k3/8/8/8/8/8/8/3NKN2 w - - 0 1",
+        ];
+        for fen in not_draws {
+            assert!(!Board::new(fen).has_insufficient_material(), "{fen}");
+        }
+    }
+
+    #[test]
+    fn chess960_castling_fields_round_trip() {
+        // X-FEN uses KQkq unless an outer rook stands beyond the castling one, then the file of the castling rook
+        for fen in [
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+            "1r2k1rr/8/8/8/8/8/8/1R2K1RR w Gg - 0 1",
+            "rk5r/8/8/8/8/8/8/RK5R b Qk - 0 1",
+        ] {
+            let board = Board::new(fen);
+            assert!(board.is_chess960(), "{fen}");
+            assert_eq!(board.to_fen(), fen);
+        }
+
+        // Shredder-FEN names every castling rook by its file and is written back as X-FEN
+        for (shredder_fen, x_fen) in [
+            (
+                "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+                "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+            ),
+            ("1r2k1rr/8/8/8/8/8/8/1R2K1RR w Gg - 0 1", "1r2k1rr/8/8/8/8/8/8/1R2K1RR w Gg - 0 1"),
+            ("rk5r/8/8/8/8/8/8/RK5R b Ah - 0 1", "rk5r/8/8/8/8/8/8/RK5R b Qk - 0 1"),
+        ] {
+            let board = Board::new(shredder_fen);
+            assert_eq!(board.to_fen(), x_fen);
+            assert_eq!(board.get_hash(), Board::new(x_fen).get_hash());
+            for color in [PieceColor::White, PieceColor::Black] {
+                for side in gamestate::CastleSide::BOTH {
+                    assert_eq!(
+                        board.get_castling_rook(color, side),
+                        Board::new(x_fen).get_castling_rook(color, side)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn chess960_position_518_is_the_standard_start() {
+        assert_eq!(Board::chess960(518).to_fen(), _INITIAL_FEN_STRING);
+        assert!(!Board::chess960(518).is_chess960());
+        assert_eq!(Board::chess960(0).to_fen(), "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1");
+        assert!(Board::chess960(0).is_chess960());
+    }
+
+    #[test]
+    fn malformed_fens_are_rejected() {
+        use fen::{FenError, FenField};
+
+        // TooManyFields is the only error without a field of its own
+        let cases = [
+            (
+                "4k3/8/8/8/8/8/8/4K3 w -",
+                FenError::MissingField {
+                    field: FenField::EnpassantSquare,
+                    offset: 23,
+                },
+                Some(FenField::EnpassantSquare),
+                23,
+            ),
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1 x", FenError::TooManyFields { offset: 30 }, None, 30),
+            (
+                "4k3/8/8/8/8/8/8/4X3 w - - 0 1",
+                FenError::UnexpectedChar {
+                    field: FenField::PiecePlacement,
+                    offset: 17,
+                    found: 'X',
+                },
+                Some(FenField::PiecePlacement),
+                17,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 x - - 0 1",
+                FenError::UnexpectedChar {
+                    field: FenField::ColorToMove,
+                    offset: 20,
+                    found: 'x',
+                },
+                Some(FenField::ColorToMove),
+                20,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w KX - 0 1",
+                FenError::UnexpectedChar {
+                    field: FenField::CastlingRights,
+                    offset: 23,
+                    found: 'X',
+                },
+                Some(FenField::CastlingRights),
+                23,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K4 w - - 0 1",
+                FenError::BadRankLength { offset: 18 },
+                Some(FenField::PiecePlacement),
+                18,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/8/4K3 w - - 0 1",
+                FenError::BadRankCount { offset: 17 },
+                Some(FenField::PiecePlacement),
+                17,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w - e4 0 1",
+                FenError::InvalidSquare { offset: 24 },
+                Some(FenField::EnpassantSquare),
+                24,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w - - x 1",
+                FenError::InvalidNumber {
+                    field: FenField::HalfmoveClock,
+                    offset: 26,
+                },
+                Some(FenField::HalfmoveClock),
+                26,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w - - 0 70000",
+                FenError::InvalidNumber {
+                    field: FenField::FullmoveNumber,
+                    offset: 28,
+                },
+                Some(FenField::FullmoveNumber),
+                28,
+            ),
+        ];
+
+        for (fen, error, field, offset) in cases {
+            assert_eq!(Board::from_fen(fen).err(), Some(error), "{fen}");
+            assert_eq!(error.field(), field, "{fen}");
+            assert_eq!(error.offset(), offset, "{fen}");
+        }
+
+        // the halfmove clock and fullmove number may be left out
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/4K3 b - -").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+    }
+
+    #[test]
+    fn illegal_positions_are_rejected() {
+        use fen::{FenError, FenField};
+        use validation::ValidationError;
+
+        let cases = [
+            (
+                "4k3/8/8/8/8/8/8/3KK3 w - - 0 1",
+                ValidationError::KingCount {
+                    color: PieceColor::White,
+                    count: 2,
+                },
+                FenField::PiecePlacement,
+                0,
+            ),
+            (
+                "4k2P/8/8/8/8/8/8/4K3 w - - 0 1",
+                ValidationError::PawnOnBackRank { square: 63 },
+                FenField::PiecePlacement,
+                0,
+            ),
+            ("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1", ValidationError::OpponentInCheck, FenField::ColorToMove, 22),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w - e6 0 1",
+                ValidationError::EnpassantSquare { square: 44 },
+                FenField::EnpassantSquare,
+                24,
+            ),
+            (
+                "4k3/8/8/8/8/8/8/4K3 w K - 0 1",
+                ValidationError::CastlingRights {
+                    color: PieceColor::White,
+                    side: gamestate::CastleSide::Kingside,
+                },
+                FenField::CastlingRights,
+                22,
+            ),
+        ];
+
+        for (fen, error, field, offset) in cases {
+            let fen_error = Board::from_fen(fen).err();
+            assert_eq!(fen_error, Some(FenError::IllegalPosition { offset, error }), "{fen}");
+            assert_eq!(error.fen_field(), field);
+            assert_eq!(fen_error.unwrap().field(), Some(field));
+        }
+    }
+
+    #[test]
+    fn builder_validates_the_position() {
+        use validation::ValidationError;
+
+        let mut board = Board::empty();
+        board
+            .put_piece(4, Piece::new(PieceType::King, PieceColor::White))
+            .put_piece(7, Piece::new(PieceType::Rook, PieceColor::White))
+            .put_piece(8, Piece::new(PieceType::Pawn, PieceColor::White))
+            .put_piece(60, Piece::new(PieceType::King, PieceColor::Black))
+            .set_side_to_move(PieceColor::Black)
+            .set_castling_rights(1);
+        assert_eq!(board.finish(), Ok(()));
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/P7/4K2R b K - 0 1");
+        assert_eq!(board.get_hash(), Board::new(&board.to_fen()).get_hash());
+
+        board.remove_piece(60);
+        assert_eq!(
+            board.finish(),
+            Err(ValidationError::KingCount {
+                color: PieceColor::Black,
+                count: 0
+            })
+        );
+
+        board
+            .put_piece(60, Piece::new(PieceType::King, PieceColor::Black))
+            .put_piece(28, Piece::new(PieceType::Rook, PieceColor::White))
+            .set_side_to_move(PieceColor::White);
+        assert_eq!(board.finish(), Err(ValidationError::OpponentInCheck));
+    }
+
+    #[test]
+    fn impossible_piece_counts_are_rejected() {
+        use validation::ValidationError;
+
+        let error = ValidationError::PawnCount {
+            color: PieceColor::White,
+            count: 9,
+        };
+        assert_eq!(
+            Board::from_fen("4k3/8/8/8/P7/8/PPPPPPPP/4K3 w - - 0 1").err(),
+            Some(fen::FenError::IllegalPosition { offset: 0, error })
+        );
+
+        let mut board = Board::new("4k3/pppppppp/8/8/8/8/8/4K3 w - - 0 1");
+        for square in 16..24 {
+            board.put_piece(square, Piece::new(PieceType::Queen, PieceColor::Black));
+        }
+        assert_eq!(
+            board.finish(),
+            Err(ValidationError::PieceCount {
+                color: PieceColor::Black,
+                count: 17
+            })
+        );
+    }
+
+    #[test]
+    fn epd_round_trips() {
+        use epd::{Epd, EpdOperand, EpdToken};
+
+        let record = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - bm Bb5 d4; am Qe2; id \"Ruy \
+                      Lopez\"; hmvc 2; fmvn 3; foo \"a b\" c;";
+        let mut epd = Epd::parse(record).unwrap();
+
+        let mut board = epd.board.clone();
+        let best_moves: Vec<_> = epd.best_moves().iter().map(|&m| san::move_to_san(&mut board, m)).collect();
+        assert_eq!(best_moves, ["Bb5", "d4"]);
+        assert_eq!(epd.avoid_moves().len(), 1);
+        assert_eq!(epd.id(), Some("Ruy Lopez"));
+        assert_eq!(epd.get("hmvc"), Some(&EpdOperand::Integer(2)));
+        assert!(epd.board.to_fen().ends_with(" 2 3"));
+        assert_eq!(
+            epd.get("foo"),
+            Some(&EpdOperand::Tokens(vec![
+                EpdToken::Text("a b".to_string()),
+                EpdToken::Word("c".to_string())
+            ]))
+        );
+        assert_eq!(epd.write(), record);
+    }
+
+    #[test]
+    fn san_round_trips() {
+        let cases = [
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nbd2"),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nfd2"),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "R1a3"),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "R5a3"),
+            ("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b8=Q+"),
+            ("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b8=N"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "O-O"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "O-O-O"),
+            ("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "Ra8#"),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "exd6"),
+        ];
+
+        for (fen, san) in cases {
+            let mut board = Board::new(fen);
+            let m = san::parse_san(&board, san).unwrap_or_else(|e| panic!("{san} in {fen}: {e}"));
+            assert_eq!(san::move_to_san(&mut board, m), san, "{fen}");
+        }
+
+        // suffixes are optional and castles can be written with zeros
+        let board = Board::new("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        assert_eq!(san::parse_san(&board, "0-0"), san::parse_san(&board, "O-O+"));
+    }
+
+    #[test]
+    fn illegal_and_ambiguous_san_is_rejected() {
+        use san::SanError;
+
+        let cases = [
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nd2", SanError::Ambiguous),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "Ra3", SanError::Ambiguous),
+            ("4k3/8/8/8/8/8/8/1N2KN2 w - - 0 1", "Nd3", SanError::Illegal),
+            ("4k3/8/8/R7/8/8/8/R3K3 w - - 0 1", "O-O-O", SanError::Illegal),
+            ("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b8", SanError::Illegal),
+            ("4k3/8/8/8/8/8/4r3/4K3 w - - 0 1", "Kf2", SanError::Illegal),
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", "Ke9", SanError::Invalid),
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", "Zf2", SanError::Invalid),
+        ];
+
+        for (fen, san, error) in cases {
+            assert_eq!(san::parse_san(&Board::new(fen), san), Err(error), "{san} in {fen}");
+        }
+    }
+
+    #[test]
+    fn undo_refuses_to_take_back_a_null_move() {
+        let mut board = Board::new("4k3/8/8/8/8/8/8/R3K3 w Q - 0 1");
+        assert_eq!(board.undo(), None);
+
+        play(&mut board, "Ra2");
+        board.make_null_move();
+        let fen = board.to_fen();
+        assert_eq!(board.undo(), None);
+        assert_eq!(board.to_fen(), fen);
+
+        board.unmake_null_move();
+        assert_eq!(board.undo().map(|m| m.get_from()), Some(0));
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/R3K3 w Q - 0 1");
+    }
+
+    #[test]
+    fn static_exchange_evaluation() {
+        let cases = [
+            ("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "Rxe5", 100),
+            ("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1", "Nxe5", -200),
+            ("4k3/8/2p5/3r4/8/8/3Q4/4K3 w - - 0 1", "Qxd5", -400),
+            ("4k3/8/2p5/3n4/4P3/8/8/4K3 w - - 0 1", "exd5", 200),
+            // the rook behind the rook recaptures after the first one is gone
+            ("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1", "Rxd5", 100),
+            ("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "exd6", 100),
+            ("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "axb8=Q", 1300),
+            ("r3k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b8=Q", -100),
+            // the king takes back only when the square is not defended
+            ("8/8/8/3k4/3p4/8/3R4/4K3 w - - 0 1", "Rxd4", -400),
+            ("8/8/8/3k4/3p4/5N2/3R4/4K3 w - - 0 1", "Rxd4", 100),
+            ("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1", "O-O", 0),
+        ];
+
+        for (fen, san, value) in cases {
+            let board = Board::new(fen);
+            let m = san::parse_san(&board, san).unwrap();
+            assert_eq!(board.see(m), value, "{san} in {fen}");
+            assert!(board.see_ge(m, value) && !board.see_ge(m, value + 1), "{san} in {fen}");
+        }
+    }
+
+    #[test]
+    fn rook_capture_removes_castling_right() {
+        let mut board = Board::new("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+        let capture = board
+            .get_legal_moves()
+            .iter()
+            .find(|m| m.get_from() == 0 && m.get_to() == 56)
+            .unwrap();
+
+        board.make_move(capture);
+        assert_eq!(board.to_fen(), "R3k2r/8/8/8/8/8/8/4K2R b Kk - 0 1");
+        assert_eq!(board.get_hash(), Board::new(&board.to_fen()).get_hash());
+
+        board.unmake_move(capture);
+        assert_eq!(board.to_fen(), "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
+    }
+
+    /// The bitboard of space separated square names
+    fn squares(names: &str) -> u64 {
+        names
+            .split_whitespace()
+            .fold(0, |acc, name| acc | 1 << str_to_square(name).unwrap())
+    }
+
+    #[test]
+    fn attackers_of_a_square() {
+        // the queen behind the bishop and the rook behind the pawn are blocked by their own pieces
+        let board = Board::new("3r4/6b1/3p4/1np5/2k5/2B1PN2/8/Q2R3K w - - 0 1");
+        let d4 = str_to_square("d4").unwrap();
+
+        assert_eq!(board.attackers_to(d4, PieceColor::White), squares("d1 c3 e3 f3"));
+        assert_eq!(board.attackers_to(d4, PieceColor::Black), squares("c4 b5 c5 g7"));
+        assert!(board.is_square_attacked(d4, PieceColor::White) && board.is_square_attacked(d4, PieceColor::Black));
+
+        let f4 = str_to_square("f4").unwrap();
+        assert!(board.is_square_attacked(f4, PieceColor::White) && !board.is_square_attacked(f4, PieceColor::Black));
+        let h5 = str_to_square("h5").unwrap();
+        assert!(!board.is_square_attacked(h5, PieceColor::White) && !board.is_square_attacked(h5, PieceColor::Black));
+
+        // the pawn that can capture en passant attacks the square behind the pawn that just moved
+        let board = Board::new("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1");
+        let d6 = str_to_square("d6").unwrap();
+        assert_eq!(board.attackers_to(d6, PieceColor::White), squares("e5"));
+        assert_eq!(board.attackers_to(d6, PieceColor::Black), 0);
+    }
+
+    #[test]
+    fn pinned_pieces() {
+        // a file pin, a diagonal pin and a queen with two pawns between it and the king, which pins neither
+        let board = Board::new("4r2k/8/8/b7/7q/4N1P1/3B1P2/4K3 w - - 0 1");
+        assert_eq!(board.pinned_pieces(PieceColor::White), squares("e3 d2"));
+        assert_eq!(board.pinned_pieces(PieceColor::Black), 0);
+        assert!(!board.is_in_check());
+
+        let pinned_targets = |from: &str| {
+            let from = str_to_square(from).unwrap();
+            board
+                .get_legal_moves()
+                .iter()
+                .filter(|m| m.get_from() == from)
+                .fold(0, |acc, m| acc | 1 << m.get_to())
+        };
+        assert_eq!(pinned_targets("e3"), 0);
+        assert_eq!(pinned_targets("d2"), squares("c3 b4 a5"));
+        assert_eq!(pinned_targets("f2"), squares("f3 f4"));
+    }
+
+    #[test]
+    fn material_signature_and_pawn_key_follow_the_game() {
+        let mut board = Board::new("r3k2r/1P6/8/8/5p2/8/4P1P1/R3K2R w KQkq - 0 1");
+        // en passant, a capturing promotion, a piece capture, a promotion and a king taking the new queen
+        let moves = "e4 fxe3 bxa8=Q+ Ke7 Qxh8 e2 Kf2 e1=Q+ Kxe1 Kd6 g4 Kc5";
+
+        let check = |board: &Board, context: &str| {
+            assert_eq!(board.get_material_signature(), board.compute_material_signature(), "{context}");
+            assert_eq!(board.get_pawn_hash(), board.compute_pawn_hash(), "{context}");
+        };
+        check(&board, "start");
+
+        let mut played = Vec::new();
+        for san in moves.split_whitespace() {
+            let m = san::parse_san(&board, san).unwrap();
+            let pawn_hash = board.get_pawn_hash();
+            let touches_pawns = m.get_moved_piece().get_type() == PieceType::Pawn
+                || board.get_piece_at(m.get_to()).is_some_and(|piece| piece.get_type() == PieceType::Pawn);
+
+            board.make_move(m);
+            check(&board, san);
+            if !touches_pawns {
+                assert_eq!(board.get_pawn_hash(), pawn_hash, "{san}");
+            }
+            played.push((san, m));
+        }
+        assert_eq!(board.get_material_signature().to_string(), "KQRRPvK");
+
+        for (san, m) in played.into_iter().rev() {
+            board.unmake_move(m);
+            check(&board, &format!("unmaking {san}"));
+        }
+        assert_eq!(board.get_material_signature().to_string(), "KRRPPPvKRRP");
+    }
+
+    #[test]
+    fn material_signature_of_mirrored_positions() {
+        let board = Board::new("8/8/4k3/8/8/2K5/1P6/R6r w - - 0 1");
+        let signature = board.get_material_signature();
+        assert_eq!(signature.to_string(), "KRPvKR");
+        assert!(board.to_string().contains("Material:     KRPvKR\n"));
+
+        let mirrored = board.mirrored();
+        assert_eq!(mirrored.get_material_signature(), signature.mirrored());
+        assert_eq!(mirrored.get_material_signature().to_string(), "KRvKRP");
+        assert_eq!(signature.mirrored().mirrored(), signature);
+        assert_ne!(signature.mirrored().key(), signature.key());
+    }
+
+    #[test]
+    fn incremental_hash_matches_recomputed_hash() {
+        // walks the tree playing a null move at every node where it's allowed, so that the test doesn't depend on the
+        // debug assertions in make_move and also runs in release builds
+        fn walk(board: &mut Board, depth: u8) {
+            assert_eq!(board.get_hash(), board.compute_hash(), "{}", board.to_fen());
+            if depth == 0 {
+                return;
+            }
+
+            if !board.is_in_check() {
+                board.make_null_move();
+                assert_eq!(board.get_hash(), board.compute_hash(), "null move in {}", board.to_fen());
+                board.unmake_null_move();
+                assert_eq!(board.get_hash(), board.compute_hash(), "null move taken back in {}", board.to_fen());
+            }
+
+            for m in generate_legal_moves(board).iter() {
+                let hash = board.get_hash();
+                board.make_move(m);
+                walk(board, depth - 1);
+                board.unmake_move(m);
+                assert_eq!(board.get_hash(), hash, "{m:?} taken back in {}", board.to_fen());
+                assert_eq!(board.get_hash(), board.compute_hash(), "{m:?} taken back in {}", board.to_fen());
+            }
+        }
+
+        // Kiwipete for castles and en passant, position 4 for promotions and a Chess960 position for its castles
+        for (fen, _) in [PERFT_POSITIONS[1], PERFT_POSITIONS[3], PERFT_POSITIONS[6]] {
+            walk(&mut Board::new(fen), 3);
+        }
+    }
+
+    #[test]
+    fn incremental_scores_match_recomputed_scores() {
+        fn scores(board: &Board) -> ([psqt::Score; 2], [psqt::Score; 2], u8) {
+            let gamestate = board.current_gamestate;
+            let colors = [PieceColor::White, PieceColor::Black];
+            (colors.map(|color| gamestate.get_material(color)), colors.map(|color| gamestate.get_psqt(color)), gamestate.get_phase())
+        }
+
+        // counts the en passant captures, promotions and castles played, so that the test can't pass without them
+        fn walk(board: &mut Board, depth: u8, special_moves: &mut [u32; 3]) {
+            assert_eq!(scores(board), board.compute_scores(), "{}", board.to_fen());
+            if depth == 0 {
+                return;
+            }
+
+            for m in generate_legal_moves(board).iter() {
+                special_moves[0] += m.is_enpassant() as u32;
+                special_moves[1] += m.is_promotion() as u32;
+                special_moves[2] += m.get_castle_side().is_some() as u32;
+
+                board.make_move(m);
+                walk(board, depth - 1, special_moves);
+                board.unmake_move(m);
+                assert_eq!(scores(board), board.compute_scores(), "{m:?} taken back in {}", board.to_fen());
+            }
+        }
+
+        // Kiwipete for en passant, position 4 for promotions and a Chess960 position for its castles
+        for (position_index, special_move_index) in [(1, 0), (3, 1), (6, 2)] {
+            let fen = PERFT_POSITIONS[position_index].0;
+            let mut special_moves = [0; 3];
+            walk(&mut Board::new(fen), 3, &mut special_moves);
+            assert!(special_moves[special_move_index] > 0, "{fen}");
+        }
+    }
+
+    #[test]
+    fn validate_finds_each_inconsistency() {
+        use validation::ValidationError;
+
+        let white = |piece_type| Piece::new(piece_type, PieceColor::White);
+        let e4 = str_to_square("e4").unwrap();
+
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        assert_eq!(board.validate(), Ok(()));
+        board.raw_parts_mut().2[e4 as usize] = Some(white(PieceType::Pawn));
+        assert_eq!(board.validate(), Err(ValidationError::PieceMatrixMismatch { square: e4 }));
+
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        board.raw_parts_mut().1[PieceColor::Black as usize] |= 1 << e4;
+        assert_eq!(board.validate(), Err(ValidationError::ColorBitboardMismatch { color: PieceColor::Black }));
+
+        let mut board = Board::new("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        board.put_piece(e4, white(PieceType::King));
+        assert_eq!(
+            board.validate(),
+            Err(ValidationError::KingCount {
+                color: PieceColor::White,
+                count: 2
+            })
+        );
+
+        let mut board = Board::new("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        board.put_piece(56, white(PieceType::Pawn));
+        assert_eq!(board.validate(), Err(ValidationError::PawnOnBackRank { square: 56 }));
+
+        let mut board = Board::new(_INITIAL_FEN_STRING);
+        board.set_en_passant(Some(e4));
+        assert_eq!(board.validate(), Err(ValidationError::EnpassantSquare { square: e4 }));
+
+        let mut board = Board::new("4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        board.set_castling_rights(1);
+        assert_eq!(
+            board.validate(),
+            Err(ValidationError::CastlingRights {
+                color: PieceColor::White,
+                side: gamestate::CastleSide::Kingside
+            })
+        );
+
+        let mut board = Board::new("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1");
+        assert_eq!(board.validate(), Ok(()));
+        board.set_side_to_move(PieceColor::White);
+        assert_eq!(board.validate(), Err(ValidationError::OpponentInCheck));
+    }
+
+    #[test]
+    fn display_of_the_starting_position() {
+        let board = Board::new(_INITIAL_FEN_STRING);
+        let gamestate_lines = format!(
+            "Side to move: White
+Castling:     KQkq
+En passant:   -
+Material:     KQRRBBNNPPPPPPPPvKQRRBBNNPPPPPPPP
+FEN:          {_INITIAL_FEN_STRING}
+Hash:         {:#018x}
+Pawn key:     {:#018x}",
+            board.get_hash(),
+            board.get_pawn_hash()
+        );
+
+        let letters = "  +-----------------+
+8 | r n b q k b n r |
+7 | p p p p p p p p |
+6 | . . . . . . . . |
+5 | . . . . . . . . |
+4 | . . . . . . . . |
+3 | . . . . . . . . |
+2 | P P P P P P P P |
+1 | R N B Q K B N R |
+  +-----------------+
+    a b c d e f g h
+";
+        assert_eq!(board.to_string(), format!("{letters}\n{gamestate_lines}"));
+
+        let figurines = "  +-----------------+
+8 | \u{265C} \u{265E} \u{265D} \u{265B} \u{265A} \u{265D} \u{265E} \u{265C} |
+7 | \u{265F} \u{265F} \u{265F} \u{265F} \u{265F} \u{265F} \u{265F} \u{265F} |
+6 | . . . . . . . . |
+5 | . . . . . . . . |
+4 | . . . . . . . . |
+3 | . . . . . . . . |
+2 | \u{2659} \u{2659} \u{2659} \u{2659} \u{2659} \u{2659} \u{2659} \u{2659} |
+1 | \u{2656} \u{2658} \u{2657} \u{2655} \u{2654} \u{2657} \u{2658} \u{2656} |
+  +-----------------+
+    a b c d e f g h
+";
+        assert_eq!(format!("{board:#}"), format!("{figurines}\n{gamestate_lines}"));
+
+        let board = Board::new("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3");
+        assert!(board.to_string().contains("\nEn passant:   f6\n"));
+    }
+
+    #[test]
+    fn bitboards_dump_layout() {
+        let dump = Board::new(_INITIAL_FEN_STRING).bitboards_dump();
+        let lines: Vec<&str> = dump.lines().collect();
+
+        // a title line, 8 ranks and a blank line for each color, rank 8 first
+        assert_eq!(lines.len(), 20);
+        assert_eq!(
+            lines[0],
+            "White Rook        White Bishop      White Queen       White Knight      White Pawn        White King        White pieces"
+        );
+        assert_eq!(
+            lines[8],
+            "1 0 0 0 0 0 0 1   0 0 1 0 0 1 0 0   0 0 0 1 0 0 0 0   0 1 0 0 0 0 1 0   0 0 0 0 0 0 0 0   0 0 0 0 1 0 0 0   1 1 1 1 1 1 1 1"
+        );
+        assert_eq!(lines[9], "");
+        assert!(lines[10].starts_with("Black Rook        Black Bishop"));
+        assert_eq!(
+            lines[12],
+            "0 0 0 0 0 0 0 0   0 0 0 0 0 0 0 0   0 0 0 0 0 0 0 0   0 0 0 0 0 0 0 0   1 1 1 1 1 1 1 1   0 0 0 0 0 0 0 0   1 1 1 1 1 1 1 1"
+        );
+    }
 }
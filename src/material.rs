@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::{fen, piece::*};
+
+/// Piece counts per color and type packed in 4 bits each, [color][type] order like pieces_bb.
+/// Equal material gives equal signatures, so it works as a key for endgame specific evaluation
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct MaterialSignature(u64);
+
+impl MaterialSignature {
+    pub const EMPTY: MaterialSignature = MaterialSignature(0);
+    pub const KINGS: MaterialSignature = Self::EMPTY.with(PieceColor::White, PieceType::King).with(PieceColor::Black, PieceType::King);
+
+    #[inline]
+    const fn shift(color: PieceColor, piece_type: PieceType) -> u64 {
+        4 * (6 * color as u64 + piece_type as u64)
+    }
+
+    /// The signature with one more piece of the given color and type
+    pub const fn with(self, color: PieceColor, piece_type: PieceType) -> MaterialSignature {
+        MaterialSignature(self.0 + (1 << Self::shift(color, piece_type)))
+    }
+
+    #[inline]
+    pub fn count(self, color: PieceColor, piece_type: PieceType) -> u8 {
+        ((self.0 >> Self::shift(color, piece_type)) & 0xF) as u8
+    }
+
+    #[inline]
+    pub fn add(&mut self, piece: Piece) {
+        self.0 += 1 << Self::shift(piece.get_color(), piece.get_type());
+    }
+
+    #[inline]
+    pub fn remove(&mut self, piece: Piece) {
+        self.0 -= 1 << Self::shift(piece.get_color(), piece.get_type());
+    }
+
+    #[inline]
+    pub fn key(self) -> u64 {
+        self.0
+    }
+
+    /// The signature with the colors swapped, so one evaluator can serve both colors of an endgame
+    #[inline]
+    pub fn mirrored(self) -> MaterialSignature {
+        MaterialSignature(((self.0 & 0xFFFFFF) << 24) | (self.0 >> 24))
+    }
+}
+
+/// Written like "KRPvKR", white's pieces first from king to pawns
+impl fmt::Display for MaterialSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ORDER: [PieceType; 6] = [
+            PieceType::King,
+            PieceType::Queen,
+            PieceType::Rook,
+            PieceType::Bishop,
+            PieceType::Knight,
+            PieceType::Pawn,
+        ];
+
+        for (i, color) in [PieceColor::White, PieceColor::Black].into_iter().enumerate() {
+            if i == 1 {
+                write!(f, "v")?;
+            }
+            for piece_type in ORDER {
+                let letter = fen::piece_to_char(Piece::new(piece_type, PieceColor::White));
+                for _ in 0..self.count(color, piece_type) {
+                    write!(f, "{letter}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
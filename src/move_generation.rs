@@ -1,26 +1,46 @@
 use crate::{
-    bitboard::{print_bitboard, Bitmanip},
+    bitboard::Bitmanip,
     board::Board,
     chess_move::Move,
     consts::CONSTS,
-    gamestate::Gamestate,
+    gamestate::CastleSide,
+    magic,
     move_list::MoveList,
     piece::*,
 };
 
+/// Which part of the legal moves generate_moves produces
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum GenType {
+    All,
+    Captures, // captures, en passant and every promotion, capturing or not
+    Quiets,   // the remaining moves, castles included
+    Evasions, // all the legal moves when in check, none otherwise
+}
+
 pub fn generate_legal_moves(board: &Board) -> MoveList {
+    generate_moves(board, GenType::All)
+}
+
+/// Generates the legal moves of the given kind. Captures and Quiets split the legal moves in two disjoint lists
+pub fn generate_moves(board: &Board, gen_type: GenType) -> MoveList {
     let mut res = MoveList::new();
     let us_color = board.get_color_to_move();
     let enemy_color = !us_color;
     let (us_pieces_bb, enemy_pieces_bb, us_color_bb, enemy_color_bb) = board.get_us_enemy_bitboards(us_color);
     let king_bit = us_pieces_bb[PieceType::King].isolate_ls1b();
 
-    let attacks_per_piece_bb = generate_attacks(enemy_pieces_bb, enemy_color_bb | (us_color_bb ^ king_bit), enemy_color);
-    let attacks_bb = attacks_per_piece_bb.iter().copied().fold(0u64, |acc, bb| acc | bb);
-
     let attackers = find_attackers(king_bit, us_color, enemy_pieces_bb, enemy_color_bb | us_color_bb);
     let num_attackers = attackers.count_ones();
 
+    if gen_type == GenType::Evasions && num_attackers == 0 {
+        return res;
+    }
+    let targets = stage_targets(board, gen_type);
+
+    let attacks_per_piece_bb = generate_attacks(enemy_pieces_bb, enemy_color_bb | (us_color_bb ^ king_bit), enemy_color);
+    let attacks_bb = attacks_per_piece_bb.iter().copied().fold(0u64, |acc, bb| acc | bb);
+
     let capture_mask = if num_attackers == 0 { 0xFFFFFFFFFFFFFFFF } else { attackers };
     let block_mask = if num_attackers == 1 && board.get_piece_at(attackers.bitscan()).unwrap().is_slider() {
         CONSTS::SQUARES_BETWEEN[king_bit.bitscan() as usize][attackers.bitscan() as usize]
@@ -35,23 +55,23 @@ pub fn generate_legal_moves(board: &Board) -> MoveList {
         let pinned_pieces_mask = pinned_pieces.iter().map(|(pinned, _)| pinned).fold(0, |acc, bb| acc | bb);
         
         if num_attackers == 0 {
-            generate_moves_for_pinned_pieces(&pinned_pieces, us_color, board, &mut res);
-
-            generate_castles(
-                king_bit,
-                us_pieces_bb[PieceType::Rook],
-                us_color_bb | enemy_color_bb,
-                attacks_bb,
-                us_color,
-                board.current_gamestate,
-                &mut res,
-            )
+            generate_moves_for_pinned_pieces(&pinned_pieces, us_color, targets, board, &mut res);
+
+            if gen_type != GenType::Captures {
+                generate_castles(king_bit, us_color_bb | enemy_color_bb, attacks_bb, us_color, board, &mut res)
+            }
         }
 
         // Sliding Piece Moves are generated using Hyperbola Quintessence
         for i in 0u8..=3u8 {
             let piece = Piece::new(PieceType::from(i), us_color);
-            generate_moves_for_piece(piece, !pinned_pieces_mask, legal_squares, board, &mut res);
+            generate_moves_for_piece(
+                piece,
+                !pinned_pieces_mask,
+                legal_squares & targets[piece.get_type()],
+                board,
+                &mut res,
+            );
         }
 
         // this is handled separately because it needs to consider the case in which an enpassant capture removes
@@ -67,14 +87,20 @@ pub fn generate_legal_moves(board: &Board) -> MoveList {
                 legal_squares.set_square(enpassant_index);
             }
         }
-        generate_moves_for_piece(piece, !pinned_pieces_mask, legal_squares, board, &mut res);
+        generate_moves_for_piece(
+            piece,
+            !pinned_pieces_mask,
+            legal_squares & targets[PieceType::Pawn],
+            board,
+            &mut res,
+        );
     }
 
     if us_pieces_bb[PieceType::King] != 0 {
         generate_king_moves(
             us_pieces_bb[PieceType::King],
             us_color_bb,
-            attacks_bb,
+            attacks_bb | !targets[PieceType::King],
             Piece::new(PieceType::King, us_color),
             &mut res,
         );
@@ -83,6 +109,32 @@ pub fn generate_legal_moves(board: &Board) -> MoveList {
     res
 }
 
+/// The squares each piece type may move to in the given stage. Pawn moves to the last rank are always promotions,
+/// so pawns get their own mask, and en passant counts as a capture even though its square is empty
+fn stage_targets(board: &Board, gen_type: GenType) -> [u64; 6] {
+    let us_color = board.get_color_to_move();
+    let (us_color_bb, enemy_color_bb) = board.get_us_enemy_colors_bb(us_color);
+    let empty_bb = !(us_color_bb | enemy_color_bb);
+    let promotion_rank = match us_color {
+        PieceColor::White => CONSTS::MASKS[63].rank_mask,
+        PieceColor::Black => CONSTS::MASKS[0].rank_mask,
+    };
+    let enpassant_bb = board
+        .current_gamestate
+        .get_enpassant_square()
+        .map_or(0, |square| 1u64 << square);
+
+    let (targets, pawn_targets) = match gen_type {
+        GenType::All | GenType::Evasions => (u64::MAX, u64::MAX),
+        GenType::Captures => (enemy_color_bb, enemy_color_bb | enpassant_bb | promotion_rank),
+        GenType::Quiets => (empty_bb, empty_bb & !enpassant_bb & !promotion_rank),
+    };
+
+    let mut res = [targets; 6];
+    res[PieceType::Pawn] = pawn_targets;
+    res
+}
+
 fn generate_attacks(pieces_bb: [u64; 6], occupied_bb: u64, piece_color: PieceColor) -> [u64; 6] {
     let mut res = [0u64; 6];
 
@@ -118,7 +170,7 @@ fn generate_rook_attacks(mut rook_bb: u64, occupied_bb: u64) -> u64 {
 
     while rook_bb != 0 {
         let index = rook_bb.bitscan_reset();
-        let moves_bb = file_moves(occupied_bb, index) + rank_moves(occupied_bb, index);
+        let moves_bb = rook_attacks(index, occupied_bb);
 
         res |= moves_bb;
     }
@@ -131,7 +183,7 @@ fn generate_bishop_attacks(mut bishop_bb: u64, occupied_bb: u64) -> u64 {
 
     while bishop_bb != 0 {
         let index = bishop_bb.bitscan_reset();
-        let moves_bb = diagonal_moves(occupied_bb, index) + antidiagonal_moves(occupied_bb, index);
+        let moves_bb = bishop_attacks(index, occupied_bb);
 
         res |= moves_bb;
     }
@@ -182,6 +234,116 @@ fn find_attackers(king_bb: u64, king_color: PieceColor, enemy_pieces_bb: [u64; 6
     attackers
 }
 
+/// Returns the pieces of attacker_color attacking the square, kings included
+pub fn attackers_to(board: &Board, square: u64, attacker_color: PieceColor) -> u64 {
+    let (attacker_pieces_bb, _, attacker_color_bb, defender_color_bb) = board.get_us_enemy_bitboards(attacker_color);
+    let square_bit = 1u64 << square;
+
+    find_attackers(
+        square_bit,
+        !attacker_color,
+        attacker_pieces_bb,
+        attacker_color_bb | defender_color_bb,
+    ) | (generate_king_attacks(square_bit) & attacker_pieces_bb[PieceType::King])
+}
+
+// indexed by PieceType: rook, bishop, queen, knight, pawn, king
+const SEE_VALUES: [i32; 6] = [500, 330, 900, 300, 100, 20000];
+const SEE_ATTACKER_ORDER: [PieceType; 6] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+    PieceType::King,
+];
+
+/// Returns the pieces of both colors still in occupied_bb that attack the square. Sliders are looked up again with
+/// the given occupancy, so removing a capturer uncovers the pieces x-raying through it
+fn attackers_with_occupancy(pieces_bb: &[[u64; 6]; 2], square_bit: u64, occupied_bb: u64) -> u64 {
+    let mut attackers = 0u64;
+
+    for color in [PieceColor::White, PieceColor::Black] {
+        attackers |= find_attackers(square_bit, !color, pieces_bb[color], occupied_bb)
+            | (generate_king_attacks(square_bit) & pieces_bb[color][PieceType::King]);
+    }
+
+    attackers & occupied_bb
+}
+
+/// Static exchange evaluation: the material the side to move wins by playing m and then both sides recapturing on
+/// the target square with their least valuable attacker, each free to stop when going on would lose material.
+/// Pins and checks are not taken into account, castles are always 0
+pub fn see(board: &Board, m: Move) -> i32 {
+    if m.get_castle_side().is_some() {
+        return 0;
+    }
+
+    let pieces_bb = board.get_pieces_bb();
+    let to = m.get_to();
+    let to_bit = 1u64 << to;
+    let (white_color_bb, black_color_bb) = board.get_us_enemy_colors_bb(PieceColor::White);
+    let mut occupied_bb = white_color_bb | black_color_bb;
+
+    let mut gains = [0i32; 32];
+    let mut depth = 0;
+    let mut color = m.get_moved_piece().get_color();
+    let mut from_bit = 1u64 << m.get_from();
+    let mut on_square_value = SEE_VALUES[m.get_moved_piece().get_type()];
+
+    if m.is_enpassant() {
+        gains[0] = SEE_VALUES[PieceType::Pawn];
+        occupied_bb ^= if color == PieceColor::White {
+            to_bit >> 8
+        } else {
+            to_bit << 8
+        };
+    } else if let Some(captured_piece) = board.get_piece_at(to) {
+        gains[0] = SEE_VALUES[captured_piece.get_type()];
+    }
+    if m.is_promotion() {
+        gains[0] += SEE_VALUES[m.get_promotion_type()] - SEE_VALUES[PieceType::Pawn];
+        on_square_value = SEE_VALUES[m.get_promotion_type()];
+    }
+
+    loop {
+        occupied_bb ^= from_bit;
+        color = !color;
+
+        let attackers = attackers_with_occupancy(&pieces_bb, to_bit, occupied_bb);
+        let Some(attacker_type) = SEE_ATTACKER_ORDER
+            .into_iter()
+            .find(|&piece_type| attackers & pieces_bb[color][piece_type] != 0)
+        else {
+            break;
+        };
+        from_bit = (attackers & pieces_bb[color][attacker_type]).isolate_ls1b();
+
+        // the king can only take when the square is not defended anymore
+        if attacker_type == PieceType::King && attackers & board.get_us_enemy_colors_bb(color).1 != 0 {
+            break;
+        }
+
+        depth += 1;
+        gains[depth] = on_square_value - gains[depth - 1];
+        on_square_value = SEE_VALUES[attacker_type];
+    }
+
+    // each side picks the better of standing pat and capturing, starting from the last capture
+    while depth > 0 {
+        gains[depth - 1] = -(-gains[depth - 1]).max(gains[depth]);
+        depth -= 1;
+    }
+
+    gains[0]
+}
+
+/// Whether the static exchange evaluation of m is at least threshold
+#[inline]
+pub fn see_ge(board: &Board, m: Move, threshold: i32) -> bool {
+    see(board, m) >= threshold
+}
+
 fn xray_rook_attacks(occupied_bb: u64, mut blockers_bb: u64, rook_bit: u64) -> u64 {
     let attacks = generate_rook_attacks(rook_bit, occupied_bb);
     blockers_bb &= attacks;
@@ -194,43 +356,20 @@ fn xray_bishop_attacks(occupied_bb: u64, mut blockers_bb: u64, bishop_bit: u64)
     attacks ^ generate_bishop_attacks(bishop_bit, occupied_bb ^ blockers_bb)
 }
 
+/// Returns the pieces of color pinned to their own king by enemy sliders
+pub fn pinned_pieces(board: &Board, color: PieceColor) -> u64 {
+    let king_bit = board.get_pieces_bb()[color][PieceType::King];
+
+    recognize_slider_pins(king_bit, board, color)
+        .iter()
+        .fold(0, |acc, (pinned, _)| acc | pinned)
+}
+
 fn recognize_pinned_pieces(king_bit: u64, board: &Board, color_to_move: PieceColor) -> Vec<(u64, u64)> {
-    let mut pinned_pieces = Vec::new();
+    let mut pinned_pieces = recognize_slider_pins(king_bit, board, color_to_move);
     let (us_pieces_bb, enemy_pieces_bb, us_color_bb, enemy_color_bb) = board.get_us_enemy_bitboards(color_to_move);
     let occupied_bb = us_color_bb | enemy_color_bb;
 
-    // Find rook and queen pins
-    let mut pinner = xray_rook_attacks(occupied_bb, us_color_bb, king_bit)
-        & (enemy_pieces_bb[PieceType::Rook] | enemy_pieces_bb[PieceType::Queen]);
-
-    while pinner != 0 {
-        let index = pinner.bitscan_reset();
-        let mut squares_between = CONSTS::SQUARES_BETWEEN[index as usize][king_bit.bitscan() as usize];
-        let pinned = squares_between & us_color_bb;
-
-        squares_between.set_square(index); // Add capturing the pinning piece to the list of legal moves
-
-        if pinned != 0 {
-            pinned_pieces.push((pinned, squares_between));
-        }
-    }
-
-    // Find bishop and queen pins
-    pinner = xray_bishop_attacks(occupied_bb, us_color_bb, king_bit)
-        & (enemy_pieces_bb[PieceType::Bishop] | enemy_pieces_bb[PieceType::Queen]);
-
-    while pinner != 0 {
-        let index = pinner.bitscan_reset();
-        let mut squares_between = CONSTS::SQUARES_BETWEEN[index as usize][king_bit.bitscan() as usize];
-        let pinned = squares_between & us_color_bb;
-
-        squares_between.set_square(index); // Add capturing the pinning piece to the list of legal moves
-
-        if pinned != 0 {
-            pinned_pieces.push((pinned, squares_between));
-        }
-    }
-
     // Check enpassant discovered check
     if let Some(enpassant_index) = board.current_gamestate.get_enpassant_square() {
         let king_index = king_bit.bitscan();
@@ -259,7 +398,51 @@ fn recognize_pinned_pieces(king_bit: u64, board: &Board, color_to_move: PieceCol
     pinned_pieces
 }
 
-fn generate_moves_for_pinned_pieces(pinned_pieces: &[(u64, u64)], us_color: PieceColor, board: &Board, move_list: &mut MoveList) {
+/// Returns every (pinned piece, squares it can still move to) pair caused by enemy sliders
+fn recognize_slider_pins(king_bit: u64, board: &Board, color: PieceColor) -> Vec<(u64, u64)> {
+    let mut pinned_pieces = Vec::new();
+    let (_, enemy_pieces_bb, us_color_bb, enemy_color_bb) = board.get_us_enemy_bitboards(color);
+    let occupied_bb = us_color_bb | enemy_color_bb;
+
+    // Find rook and queen pins
+    let mut pinner = xray_rook_attacks(occupied_bb, us_color_bb, king_bit)
+        & (enemy_pieces_bb[PieceType::Rook] | enemy_pieces_bb[PieceType::Queen]);
+
+    while pinner != 0 {
+        let index = pinner.bitscan_reset() as usize;
+        let king_index = king_bit.bitscan() as usize;
+        let pinned = CONSTS::SQUARES_BETWEEN[index][king_index] & us_color_bb;
+
+        // The pinned piece keeps to the line, its moves along it stop at the king and at the pinner, which it may capture
+        if pinned != 0 {
+            pinned_pieces.push((pinned, CONSTS::LINE_THROUGH[index][king_index]));
+        }
+    }
+
+    // Find bishop and queen pins
+    pinner = xray_bishop_attacks(occupied_bb, us_color_bb, king_bit)
+        & (enemy_pieces_bb[PieceType::Bishop] | enemy_pieces_bb[PieceType::Queen]);
+
+    while pinner != 0 {
+        let index = pinner.bitscan_reset() as usize;
+        let king_index = king_bit.bitscan() as usize;
+        let pinned = CONSTS::SQUARES_BETWEEN[index][king_index] & us_color_bb;
+
+        if pinned != 0 {
+            pinned_pieces.push((pinned, CONSTS::LINE_THROUGH[index][king_index]));
+        }
+    }
+
+    pinned_pieces
+}
+
+fn generate_moves_for_pinned_pieces(
+    pinned_pieces: &[(u64, u64)],
+    us_color: PieceColor,
+    targets: [u64; 6],
+    board: &Board,
+    move_list: &mut MoveList,
+) {
     let us_pieces_bb = board.get_pieces_bb()[us_color];
 
     for &(pinned_bb, restriction_mask) in pinned_pieces {
@@ -272,7 +455,13 @@ fn generate_moves_for_pinned_pieces(pinned_pieces: &[(u64, u64)], us_color: Piec
         }
 
         if let Some(piece) = pinned_piece {
-            generate_moves_for_piece(piece, pinned_bb, restriction_mask, board, move_list);
+            generate_moves_for_piece(
+                piece,
+                pinned_bb,
+                restriction_mask & targets[piece.get_type()],
+                board,
+                move_list,
+            );
         }
     }
 }
@@ -297,7 +486,7 @@ fn generate_white_pawns_moves(
     pawns_bb: u64,
     us_color_bb: u64,
     mut enemy_color_bb: u64,
-    mut legal_squares_bb: u64,
+    legal_squares_bb: u64,
     piece: Piece,
     opt_enpassant_square: Option<u64>,
     move_list: &mut MoveList,
@@ -326,7 +515,7 @@ fn generate_black_pawns_moves(
     pawns_bb: u64,
     us_color_bb: u64,
     mut enemy_color_bb: u64,
-    mut legal_squares_bb: u64,
+    legal_squares_bb: u64,
     piece: Piece,
     opt_enpassant_square: Option<u64>,
     move_list: &mut MoveList,
@@ -376,8 +565,7 @@ fn generate_bishop_moves(
 
     while bishop_bb != 0 {
         let index = bishop_bb.bitscan_reset();
-        let moves_bb =
-            (diagonal_moves(blockers_bb, index) + antidiagonal_moves(blockers_bb, index)) & !us_color_bb & legal_squares_bb;
+        let moves_bb = bishop_attacks(index, blockers_bb) & !us_color_bb & legal_squares_bb;
 
         move_list.append_bb(moves_bb, index, piece);
     }
@@ -395,7 +583,7 @@ fn generate_rook_moves(
 
     while rook_bb != 0 {
         let index = rook_bb.bitscan_reset();
-        let moves_bb = (file_moves(blockers_bb, index) + rank_moves(blockers_bb, index)) & !us_color_bb & legal_squares_bb;
+        let moves_bb = rook_attacks(index, blockers_bb) & !us_color_bb & legal_squares_bb;
 
         move_list.append_bb(moves_bb, index, piece);
     }
@@ -413,6 +601,37 @@ fn generate_queen_moves(
     generate_rook_moves(queen_bb, us_color_bb, enemy_color_bb, legal_squares_bb, piece, move_list);
 }
 
+/// Rook attacks from index, looked up in the magic tables when the magic or pext feature is enabled and computed
+/// with hyperbola quintessence otherwise
+#[inline]
+pub fn rook_attacks(index: u64, blockers_bb: u64) -> u64 {
+    if cfg!(feature = "magic") {
+        magic::rook_attacks(index, blockers_bb)
+    } else {
+        hyperbola_rook_attacks(index, blockers_bb)
+    }
+}
+
+/// Bishop attacks from index, see rook_attacks for the backends
+#[inline]
+pub fn bishop_attacks(index: u64, blockers_bb: u64) -> u64 {
+    if cfg!(feature = "magic") {
+        magic::bishop_attacks(index, blockers_bb)
+    } else {
+        hyperbola_bishop_attacks(index, blockers_bb)
+    }
+}
+
+#[inline]
+pub fn hyperbola_rook_attacks(index: u64, blockers_bb: u64) -> u64 {
+    file_moves(blockers_bb, index) + rank_moves(blockers_bb, index)
+}
+
+#[inline]
+pub fn hyperbola_bishop_attacks(index: u64, blockers_bb: u64) -> u64 {
+    diagonal_moves(blockers_bb, index) + antidiagonal_moves(blockers_bb, index)
+}
+
 fn diagonal_moves(blockers_bb: u64, index: u64) -> u64 {
     let mut forward: u64;
     let mut reverse: u64;
@@ -521,34 +740,44 @@ fn generate_moves_for_piece(
     }
 }
 
+/// Works for Chess960 as well: the squares between king and rook and their destinations must be empty (apart from the
+/// castling king and rook themselves) and the king can't cross an attacked square
 fn generate_castles(
     king_bit: u64,
-    rooks_bb: u64,
     occupied_bb: u64,
     attacked_bb: u64,
     us_color: PieceColor,
-    gamestate: Gamestate,
+    board: &Board,
     move_list: &mut MoveList,
 ) {
-    if gamestate.can_castle_kingside(us_color)
-        && CONSTS::CASTLING_MASKS_KINGSIDE[us_color] & (occupied_bb | attacked_bb) == 0
-        && CONSTS::CASTLING_ROOK_INDEX_KINGSIDE[us_color] & rooks_bb != 0
-    {
-        let king_index = king_bit.bitscan();
-        let mut m = Move::new(king_index, king_index + 2, Piece::new(PieceType::King, us_color));
-        m.add_castle_kingside();
+    let (us_pieces_bb, enemy_pieces_bb, _, _) = board.get_us_enemy_bitboards(us_color);
+    let king_index = king_bit.bitscan();
 
-        move_list.push(m);
-    }
+    for side in CastleSide::BOTH {
+        let rook_from = board.get_castling_rook(us_color, side);
+        let rook_bit = 1u64 << rook_from;
 
-    if gamestate.can_castle_queenside(us_color)
-        && CONSTS::CASTLING_EMPTY_MASKS_QUEENSIDE[us_color] & occupied_bb == 0
-        && CONSTS::CASTLING_ATTACKED_MASKS_QUEENSIDE[us_color] & attacked_bb == 0
-        && CONSTS::CASTLING_ROOK_INDEX_QUEENSIDE[us_color] & rooks_bb != 0
-    {
-        let king_index = king_bit.bitscan();
-        let mut m = Move::new(king_index, king_index - 2, Piece::new(PieceType::King, us_color));
-        m.add_castle_queenside();
+        if !board.current_gamestate.can_castle(us_color, side) || us_pieces_bb[PieceType::Rook] & rook_bit == 0 {
+            continue;
+        }
+
+        let king_to = CONSTS::CASTLING_KING_DESTINATION[us_color][side as usize];
+        let rook_to = CONSTS::CASTLING_ROOK_DESTINATION[us_color][side as usize];
+        let king_path = CONSTS::SQUARES_BETWEEN[king_index as usize][king_to as usize] | (1u64 << king_to);
+        let rook_path = CONSTS::SQUARES_BETWEEN[rook_from as usize][rook_to as usize] | (1u64 << rook_to);
+
+        if (king_path | rook_path) & occupied_bb & !(king_bit | rook_bit) != 0 || king_path & attacked_bb != 0 {
+            continue;
+        }
+
+        // the castling rook itself could be shielding the king destination from a slider on the back rank
+        if find_attackers(1u64 << king_to, us_color, enemy_pieces_bb, occupied_bb ^ king_bit ^ rook_bit) != 0 {
+            continue;
+        }
+
+        let to = if board.is_chess960() { rook_from } else { king_to };
+        let mut m = Move::new(king_index, to, Piece::new(PieceType::King, us_color));
+        m.add_castle(side);
 
         move_list.push(m);
     }
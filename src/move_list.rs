@@ -32,7 +32,7 @@ impl MoveList {
         self.size
     }
 
-    pub fn iter(&self) -> MoveListIter {
+    pub fn iter(&self) -> MoveListIter<'_> {
         MoveListIter {
             movelist: self,
             index: 0,
@@ -52,7 +52,7 @@ impl<T, const N: usize> IndexMut<PieceType> for [T; N] {
 
 // ---------------------------------------------------------------------------------------------------
 
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug)]
 pub enum PieceColor {
     White,
     Black,
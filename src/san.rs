@@ -0,0 +1,199 @@
+use std::fmt;
+
+use crate::{
+    board::Board, chess_move::Move, fen, gamestate::CastleSide, move_generation::generate_legal_moves, piece::*, square_to_str,
+    str_to_square,
+};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SanError {
+    Invalid,   // not shaped like a SAN move
+    Illegal,   // no legal move matches
+    Ambiguous, // more than one legal move matches
+}
+
+impl fmt::Display for SanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SanError::Invalid => write!(f, "not a SAN move"),
+            SanError::Illegal => write!(f, "no legal move matches"),
+            SanError::Ambiguous => write!(f, "more than one legal move matches"),
+        }
+    }
+}
+
+impl std::error::Error for SanError {}
+
+/// Resolves a SAN move like "Nbd7", "exd8=Q+" or "O-O" against the legal moves of the position.
+/// Check and annotation suffixes are ignored, castles can be written with zeros too
+pub fn parse_san(board: &Board, san: &str) -> Result<Move, SanError> {
+    let san = san.trim_end_matches(['+', '#', '!', '?']);
+    let legal_moves = generate_legal_moves(board);
+
+    let castle_side = match san {
+        "O-O" | "0-0" => Some(CastleSide::Kingside),
+        "O-O-O" | "0-0-0" => Some(CastleSide::Queenside),
+        _ => None,
+    };
+    if let Some(side) = castle_side {
+        return legal_moves
+            .iter()
+            .find(|m| m.get_castle_side() == Some(side))
+            .ok_or(SanError::Illegal);
+    }
+
+    if !san.is_ascii() || san.len() < 2 {
+        return Err(SanError::Invalid);
+    }
+    let mut rest = san;
+
+    // the promotion piece, the equal sign is optional
+    let mut promotion_type = None;
+    if let Some(c) = rest.chars().last().filter(|c| "NBRQ".contains(*c)) {
+        promotion_type = Some(letter_to_piece_type(c).ok_or(SanError::Invalid)?);
+        rest = rest[..rest.len() - 1].trim_end_matches('=');
+    }
+
+    if rest.len() < 2 {
+        return Err(SanError::Invalid);
+    }
+    let to = str_to_square(&rest[rest.len() - 2..]).ok_or(SanError::Invalid)?;
+    rest = &rest[..rest.len() - 2];
+
+    let mut moved_type = PieceType::Pawn;
+    if let Some(c) = rest.chars().next().filter(|c| c.is_ascii_uppercase()) {
+        moved_type = letter_to_piece_type(c).ok_or(SanError::Invalid)?;
+        rest = &rest[1..];
+    }
+
+    // what is left is the optional disambiguation followed by the optional capture sign
+    let mut from_file = None;
+    let mut from_rank = None;
+    for c in rest.trim_end_matches('x').chars() {
+        match c {
+            'a'..='h' if from_file.is_none() && from_rank.is_none() => from_file = Some(c as u64 - 'a' as u64),
+            '1'..='8' if from_rank.is_none() => from_rank = Some(c as u64 - '1' as u64),
+            _ => return Err(SanError::Invalid),
+        }
+    }
+
+    let mut candidates = legal_moves.iter().filter(|m| {
+        m.get_castle_side().is_none()
+            && m.get_moved_piece().get_type() == moved_type
+            && m.get_to() == to
+            && from_file.is_none_or(|file| m.get_from() % 8 == file)
+            && from_rank.is_none_or(|rank| m.get_from() / 8 == rank)
+            && match promotion_type {
+                Some(promotion_type) => m.is_promotion() && m.get_promotion_type() == promotion_type,
+                None => !m.is_promotion(),
+            }
+    });
+
+    match (candidates.next(), candidates.next()) {
+        (Some(m), None) => Ok(m),
+        (Some(_), Some(_)) => Err(SanError::Ambiguous),
+        (None, _) => Err(SanError::Illegal),
+    }
+}
+
+/// Writes a sequence of moves played from the given position as numbered SAN, like "1. e4 e5 2. Nf3". The moves are
+/// played on the board
+pub fn moves_to_san(board: &mut Board, moves: &[Move]) -> String {
+    let mut res = String::new();
+    let mut fullmove_number = board.current_gamestate.get_fullmove_number();
+
+    for (i, &m) in moves.iter().enumerate() {
+        match board.get_color_to_move() {
+            PieceColor::White => res.push_str(&format!("{fullmove_number}. ")),
+            PieceColor::Black if i == 0 => res.push_str(&format!("{fullmove_number}... ")),
+            PieceColor::Black => {}
+        }
+
+        res.push_str(&move_to_san(board, m));
+        res.push(' ');
+
+        if board.get_color_to_move() == PieceColor::Black {
+            fullmove_number += 1;
+        }
+        board.make_move(m);
+    }
+
+    res.truncate(res.trim_end().len());
+    res
+}
+
+/// Writes a legal move in SAN with the shortest disambiguation and a check or mate suffix.
+/// The move is played and taken back to find out whether it gives check
+pub fn move_to_san(board: &mut Board, m: Move) -> String {
+    let mut res = match m.get_castle_side() {
+        Some(CastleSide::Kingside) => String::from("O-O"),
+        Some(CastleSide::Queenside) => String::from("O-O-O"),
+        None => {
+            let from = m.get_from();
+            let to = m.get_to();
+            let moved_type = m.get_moved_piece().get_type();
+            let is_capture = board.get_piece_at(to).is_some() || m.is_enpassant();
+            let mut res = String::with_capacity(7);
+
+            if moved_type == PieceType::Pawn {
+                if is_capture {
+                    res.push((b'a' + (from % 8) as u8) as char);
+                }
+            } else {
+                res.push(piece_type_to_letter(moved_type));
+
+                let mut same_file = false;
+                let mut same_rank = false;
+                let mut ambiguous = false;
+                for other in generate_legal_moves(board).iter() {
+                    if other.get_castle_side().is_none()
+                        && other.get_moved_piece().get_type() == moved_type
+                        && other.get_to() == to
+                        && other.get_from() != from
+                    {
+                        ambiguous = true;
+                        same_file |= other.get_from() % 8 == from % 8;
+                        same_rank |= other.get_from() / 8 == from / 8;
+                    }
+                }
+
+                if ambiguous && (!same_file || same_rank) {
+                    res.push((b'a' + (from % 8) as u8) as char);
+                }
+                if same_file {
+                    res.push((b'1' + (from / 8) as u8) as char);
+                }
+            }
+
+            if is_capture {
+                res.push('x');
+            }
+            res.push_str(&square_to_str(to));
+
+            if m.is_promotion() {
+                res.push('=');
+                res.push(piece_type_to_letter(m.get_promotion_type()));
+            }
+            res
+        }
+    };
+
+    board.make_move(m);
+    if board.is_in_check() {
+        res.push(if generate_legal_moves(board).len() == 0 { '#' } else { '+' });
+    }
+    board.unmake_move(m);
+
+    res
+}
+
+fn letter_to_piece_type(c: char) -> Option<PieceType> {
+    match c {
+        'K' | 'Q' | 'R' | 'B' | 'N' => fen::char_to_piece(c).map(|piece| piece.get_type()),
+        _ => None,
+    }
+}
+
+fn piece_type_to_letter(piece_type: PieceType) -> char {
+    fen::piece_to_char(Piece::new(piece_type, PieceColor::White))
+}
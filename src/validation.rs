@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::{fen::FenField, gamestate::CastleSide, piece::PieceColor, square_to_str};
+
+/// The first inconsistency found by Board::validate
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ValidationError {
+    OverlappingPieces { square: u64 },
+    ColorBitboardMismatch { color: PieceColor },
+    PieceMatrixMismatch { square: u64 },
+    KingCount { color: PieceColor, count: u32 },
+    PawnCount { color: PieceColor, count: u32 },
+    PieceCount { color: PieceColor, count: u32 },
+    PawnOnBackRank { square: u64 },
+    OpponentInCheck,
+    EnpassantSquare { square: u64 },
+    CastlingRights { color: PieceColor, side: CastleSide },
+    ScoreMismatch,
+    MaterialSignatureMismatch,
+    PawnHashMismatch,
+    HashMismatch { stored: u64, computed: u64 },
+}
+
+impl ValidationError {
+    /// The FEN field describing the part of the position that is wrong
+    pub fn fen_field(&self) -> FenField {
+        match self {
+            ValidationError::OpponentInCheck => FenField::ColorToMove,
+            ValidationError::EnpassantSquare { .. } => FenField::EnpassantSquare,
+            ValidationError::CastlingRights { .. } => FenField::CastlingRights,
+            _ => FenField::PiecePlacement,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::OverlappingPieces { square } => {
+                write!(f, "more than one piece bitboard contains {}", square_to_str(*square))
+            }
+            ValidationError::ColorBitboardMismatch { color } => {
+                write!(f, "{color:?} color bitboard is not the union of its piece bitboards")
+            }
+            ValidationError::PieceMatrixMismatch { square } => {
+                write!(f, "piece matrix and bitboards disagree on {}", square_to_str(*square))
+            }
+            ValidationError::KingCount { color, count } => write!(f, "{color:?} has {count} kings"),
+            ValidationError::PawnCount { color, count } => write!(f, "{color:?} has {count} pawns, at most 8 are possible"),
+            ValidationError::PieceCount { color, count } => {
+                write!(f, "{color:?} has {count} pieces, at most 16 are possible")
+            }
+            ValidationError::PawnOnBackRank { square } => write!(f, "pawn on back rank square {}", square_to_str(*square)),
+            ValidationError::OpponentInCheck => write!(f, "the side not to move is in check"),
+            ValidationError::EnpassantSquare { square } => {
+                write!(f, "{} is not a valid en passant square", square_to_str(*square))
+            }
+            ValidationError::CastlingRights { color, side } => {
+                write!(
+                    f,
+                    "{color:?} can castle {side:?} but king or rook are not on their starting squares"
+                )
+            }
+            ValidationError::ScoreMismatch => write!(f, "material, piece-square or phase scores differ from the computed ones"),
+            ValidationError::MaterialSignatureMismatch => write!(f, "material signature differs from the piece counts"),
+            ValidationError::PawnHashMismatch => write!(f, "stored pawn hash differs from the computed one"),
+            ValidationError::HashMismatch { stored, computed } => {
+                write!(
+                    f,
+                    "stored hash {stored:#018x} differs from the computed hash {computed:#018x}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
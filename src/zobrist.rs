@@ -0,0 +1,88 @@
+use crate::piece::*;
+
+pub struct ZobristKeys {
+    pieces: [[[u64; 64]; 6]; 2],
+    black_to_move: u64,
+    castling_rights: [u64; 16],
+    enpassant_file: [u64; 8],
+}
+
+/// Keys are generated at compile time with a fixed seed, so hashes are the same on every run
+pub static ZOBRIST: ZobristKeys = ZobristKeys::new(0x2545F4914F6CDD1D);
+
+impl ZobristKeys {
+    const fn new(seed: u64) -> ZobristKeys {
+        let mut state = seed;
+        let mut keys = ZobristKeys {
+            pieces: [[[0; 64]; 6]; 2],
+            black_to_move: 0,
+            castling_rights: [0; 16],
+            enpassant_file: [0; 8],
+        };
+
+        let mut color = 0;
+        while color < 2 {
+            let mut piece_type = 0;
+            while piece_type < 6 {
+                let mut square = 0;
+                while square < 64 {
+                    state = xorshift64(state);
+                    keys.pieces[color][piece_type][square] = state;
+                    square += 1;
+                }
+                piece_type += 1;
+            }
+            color += 1;
+        }
+
+        state = xorshift64(state);
+        keys.black_to_move = state;
+
+        // every combination of rights gets its own key so rights can be swapped with a single xor
+        let mut rights = 1;
+        while rights < 16 {
+            state = xorshift64(state);
+            keys.castling_rights[rights] = state;
+            rights += 1;
+        }
+
+        let mut file = 0;
+        while file < 8 {
+            state = xorshift64(state);
+            keys.enpassant_file[file] = state;
+            file += 1;
+        }
+
+        keys
+    }
+
+    #[inline]
+    pub fn piece(&self, piece: Piece, square: u64) -> u64 {
+        self.pieces[piece.get_color()][piece.get_type()][square as usize]
+    }
+
+    #[inline]
+    pub fn black_to_move(&self) -> u64 {
+        self.black_to_move
+    }
+
+    #[inline]
+    pub fn castling_rights(&self, castling_rights: u8) -> u64 {
+        self.castling_rights[castling_rights as usize]
+    }
+
+    #[inline]
+    pub fn enpassant_square(&self, opt_enpassant_square: Option<u64>) -> u64 {
+        match opt_enpassant_square {
+            Some(square) => self.enpassant_file[(square % 8) as usize],
+            None => 0,
+        }
+    }
+}
+
+const fn xorshift64(mut state: u64) -> u64 {
+    state ^= state << 13;
+    state ^= state >> 7;
+    state ^= state << 17;
+    state
+}